@@ -1,12 +1,50 @@
 use criterion::{
-    criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, Criterion,
+    black_box, criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, Criterion,
 };
 use crypto_bigint::{
     modular::runtime_mod::{DynResidue, DynResidueParams},
-    NonZero, Random, Reciprocal, Uint, U256,
+    Limb, NonZero, Random, Reciprocal, Uint, U256, U384,
 };
 use rand_core::OsRng;
 
+fn bench_arithmetic<'a, M: Measurement, const LIMBS: usize>(group: &mut BenchmarkGroup<'a, M>) {
+    const TEST_SET: usize = 10;
+    let bits = Uint::<LIMBS>::BITS;
+    let xs = (0..TEST_SET)
+        .map(|_| Uint::<LIMBS>::random(&mut OsRng))
+        .collect::<Vec<_>>();
+    let ys = (0..TEST_SET)
+        .map(|_| Uint::<LIMBS>::random(&mut OsRng))
+        .collect::<Vec<_>>();
+
+    group.bench_function(format!("wrapping_add, {} bits", bits), |b| {
+        b.iter(|| {
+            xs.iter()
+                .zip(ys.iter())
+                .map(|(x, y)| black_box(black_box(x).wrapping_add(black_box(y))))
+                .for_each(drop)
+        })
+    });
+
+    group.bench_function(format!("wrapping_sub, {} bits", bits), |b| {
+        b.iter(|| {
+            xs.iter()
+                .zip(ys.iter())
+                .map(|(x, y)| black_box(black_box(x).wrapping_sub(black_box(y))))
+                .for_each(drop)
+        })
+    });
+
+    group.bench_function(format!("mul_wide, {} bits", bits), |b| {
+        b.iter(|| {
+            xs.iter()
+                .zip(ys.iter())
+                .map(|(x, y)| black_box(black_box(x).mul_wide(black_box(y))))
+                .for_each(drop)
+        })
+    });
+}
+
 fn bench_division<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     const TEST_SET: usize = 10;
     let xs = (0..TEST_SET)
@@ -33,6 +71,35 @@ fn bench_division<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
         })
     });
 
+    let xs_wide = (0..TEST_SET)
+        .map(|_| Uint::<8>::random(&mut OsRng))
+        .collect::<Vec<_>>();
+    let ys_narrow = (0..TEST_SET)
+        .map(|_| NonZero::new(Uint::<4>::random(&mut OsRng)).unwrap())
+        .collect::<Vec<_>>();
+    let ys_resized = ys_narrow
+        .iter()
+        .map(|y| NonZero::new(y.resize::<8>()).unwrap())
+        .collect::<Vec<_>>();
+    group.bench_function("div/rem, 8/4, full size", |b| {
+        b.iter(|| {
+            xs_wide
+                .iter()
+                .zip(ys_resized.iter())
+                .map(|(x, y)| x.div_rem(y))
+                .for_each(drop)
+        })
+    });
+    group.bench_function("div_rem_wide, 8/4", |b| {
+        b.iter(|| {
+            xs_wide
+                .iter()
+                .zip(ys_narrow.iter())
+                .map(|(x, y)| x.div_rem_wide(y))
+                .for_each(drop)
+        })
+    });
+
     let ys = (0..TEST_SET)
         .map(|_| Uint::<1>::random(&mut OsRng))
         .collect::<Vec<_>>();
@@ -102,12 +169,112 @@ fn bench_modpow<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
                 .for_each(drop)
         })
     });
+
+    let public_exponent = U256::from(65537u64);
+
+    group.bench_function("modpow, 4^4, exponent 65537", |b| {
+        b.iter(|| xs_m.iter().map(|x| x.pow(&public_exponent)).for_each(drop))
+    });
+
+    group.bench_function("modpow_vartime, 4^4, exponent 65537", |b| {
+        b.iter(|| {
+            xs_m.iter()
+                .map(|x| x.pow_vartime(&public_exponent))
+                .for_each(drop)
+        })
+    });
+}
+
+#[cfg(feature = "alloc")]
+fn bench_fixed_base<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    const TEST_SET: usize = 1000;
+    let modulus = U256::random(&mut OsRng) | U256::ONE;
+    let params = DynResidueParams::new(&modulus);
+    let base = DynResidue::new(&U256::random(&mut OsRng), params);
+    let exponents = (0..TEST_SET)
+        .map(|_| U256::random(&mut OsRng))
+        .collect::<Vec<_>>();
+
+    group.bench_function("modpow, 4^4, 1000 exponents", |b| {
+        b.iter(|| exponents.iter().map(|e| base.pow(e)).for_each(drop))
+    });
+
+    group.bench_function("fixed-base modpow, 4^4, 1000 exponents", |b| {
+        b.iter(|| {
+            let table = base.precompute_base();
+            exponents.iter().map(|e| table.pow(e)).for_each(drop)
+        })
+    });
+}
+
+fn bench_mul_mod_special<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    const TEST_SET: usize = 10;
+    // 2^256 - 189
+    let c = Limb(189);
+    let p = U256::ZERO.wrapping_sub(&U256::from_word(c.0));
+    let p_nz = NonZero::new(p).unwrap();
+    let xs = (0..TEST_SET)
+        .map(|_| U256::random(&mut OsRng).rem(&p_nz))
+        .collect::<Vec<_>>();
+    let ys = (0..TEST_SET)
+        .map(|_| U256::random(&mut OsRng).rem(&p_nz))
+        .collect::<Vec<_>>();
+
+    group.bench_function("mul_mod_special, 4*4, 2^256 - 189", |b| {
+        b.iter(|| {
+            xs.iter()
+                .zip(ys.iter())
+                .map(|(x, y)| x.mul_mod_special(y, c))
+                .for_each(drop)
+        })
+    });
+
+    let params = DynResidueParams::new(&p);
+    let xs_m = xs
+        .iter()
+        .map(|x| DynResidue::new(x, params))
+        .collect::<Vec<_>>();
+    let ys_m = ys
+        .iter()
+        .map(|y| DynResidue::new(y, params))
+        .collect::<Vec<_>>();
+
+    group.bench_function("modmul, 4*4, 2^256 - 189", |b| {
+        b.iter(|| {
+            xs_m.iter()
+                .zip(ys_m.iter())
+                .map(|(x, y)| x * y)
+                .for_each(drop)
+        })
+    });
+}
+
+fn bench_montgomery_ops<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let params = DynResidueParams::new(&(U256::random(&mut OsRng) | U256::ONE));
+    let x = DynResidue::new(&U256::random(&mut OsRng), params);
+
+    group.bench_function("square, 4", |b| b.iter(|| black_box(black_box(x).square())));
+
+    group.bench_function("mul(self, self), 4", |b| {
+        b.iter(|| {
+            let x = black_box(x);
+            black_box(x.mul(&x))
+        })
+    });
+
+    group.bench_function("double, 4", |b| b.iter(|| black_box(black_box(x).double())));
 }
 
 fn bench_wrapping_ops(c: &mut Criterion) {
     let mut group = c.benchmark_group("wrapping ops");
+    bench_arithmetic::<_, { U256::LIMBS }>(&mut group);
+    bench_arithmetic::<_, { U384::LIMBS }>(&mut group);
     bench_division(&mut group);
     bench_modpow(&mut group);
+    #[cfg(feature = "alloc")]
+    bench_fixed_base(&mut group);
+    bench_mul_mod_special(&mut group);
+    bench_montgomery_ops(&mut group);
     group.finish();
 }
 
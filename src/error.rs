@@ -0,0 +1,25 @@
+//! Error types.
+
+use core::fmt;
+
+/// Errors which can occur when encoding or decoding integers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The length of the buffer or input does not match the encoded size of the value.
+    InvalidLength,
+    /// The input contains a character which is not a hexadecimal digit.
+    InvalidHexDigit,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength => f.write_str("length does not match the encoded size"),
+            Error::InvalidHexDigit => f.write_str("invalid hexadecimal digit"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
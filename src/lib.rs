@@ -151,9 +151,12 @@
 //! [`Rem`]: core::ops::Rem
 //! [`Sub`]: core::ops::Sub
 
-#[cfg(all(feature = "alloc", test))]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 mod nlimbs;
 
@@ -161,15 +164,20 @@ mod nlimbs;
 mod array;
 mod checked;
 mod ct_choice;
+mod error;
 mod limb;
 mod non_zero;
 mod traits;
 mod uint;
 mod wrapping;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use crate::{
     checked::Checked,
     ct_choice::CtChoice,
+    error::Error,
     limb::{Limb, WideWord, Word},
     non_zero::NonZero,
     traits::*,
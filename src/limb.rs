@@ -1,7 +1,7 @@
 //! Big integers are represented as an array of smaller CPU word-size integers
 //! called "limbs".
 
-#![allow(clippy::derive_hash_xor_eq)]
+#![allow(clippy::derived_hash_with_manual_eq)]
 
 mod add;
 mod bit_and;
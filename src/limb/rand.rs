@@ -15,6 +15,12 @@ impl Random for Limb {
     fn random(rng: &mut impl CryptoRngCore) -> Self {
         Self(rng.next_u64())
     }
+
+    fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        let mut bytes = <Self as Encoding>::Repr::default();
+        rng.try_fill_bytes(&mut bytes)?;
+        Ok(Limb::from_le_bytes(bytes))
+    }
 }
 
 impl RandomMod for Limb {
@@ -35,4 +41,25 @@ impl RandomMod for Limb {
             }
         }
     }
+
+    fn try_random_mod(
+        rng: &mut impl CryptoRngCore,
+        modulus: &NonZero<Self>,
+    ) -> Result<Self, rand_core::Error> {
+        let mut bytes = <Self as Encoding>::Repr::default();
+
+        let n_bits = modulus.bits();
+        let n_bytes = (n_bits + 7) / 8;
+        let mask = 0xff >> (8 * n_bytes - n_bits);
+
+        loop {
+            rng.try_fill_bytes(&mut bytes[..n_bytes])?;
+            bytes[n_bytes - 1] &= mask;
+
+            let n = Limb::from_le_bytes(bytes);
+            if n.ct_lt(modulus).into() {
+                return Ok(n);
+            }
+        }
+    }
 }
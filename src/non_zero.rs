@@ -131,6 +131,16 @@ where
             }
         }
     }
+
+    /// Generate a random `NonZero<T>`, returning an error if `rng` fails.
+    fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        // See `NonZero::random` regarding rejection sampling.
+        loop {
+            if let Some(result) = Self::new(T::try_random(rng)?).into() {
+                break Ok(result);
+            }
+        }
+    }
 }
 
 impl<T> fmt::Display for NonZero<T>
@@ -0,0 +1,25 @@
+//! Helpers shared between unit tests.
+
+use core::hash::{Hash, Hasher};
+
+/// 64-bit FNV-1a, to check hashes without depending on `std`.
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+/// Hashes `value` with a fixed, platform-independent hasher.
+pub(crate) fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv1a(0xcbf29ce484222325);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
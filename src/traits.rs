@@ -2,7 +2,10 @@
 
 use crate::{Limb, NonZero};
 use core::fmt::Debug;
-use core::ops::{BitAnd, BitOr, BitXor, Div, Not, Rem, Shl, Shr};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Div, Mul, MulAssign, Neg, Not, Rem, Shl, Shr, Sub,
+    SubAssign,
+};
 use subtle::{
     Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
     CtOption,
@@ -93,6 +96,15 @@ pub trait Zero: ConstantTimeEq + Sized {
 pub trait Random: Sized {
     /// Generate a cryptographically secure random value.
     fn random(rng: &mut impl CryptoRngCore) -> Self;
+
+    /// Generate a cryptographically secure random value, returning an error
+    /// instead of panicking if `rng` fails.
+    ///
+    /// The default implementation calls [`Random::random`], and therefore never returns
+    /// an error; implementations should override it to propagate failures of `rng`.
+    fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        Ok(Self::random(rng))
+    }
 }
 
 /// Modular random number generation support.
@@ -110,6 +122,20 @@ pub trait RandomMod: Sized + Zero {
     /// CSRNG, where previous outputs are unrelated to subsequent
     /// outputs and do not reveal information about the RNG's internal state.
     fn random_mod(rng: &mut impl CryptoRngCore, modulus: &NonZero<Self>) -> Self;
+
+    /// Generate a cryptographically secure random number which is less than
+    /// a given `modulus`, returning an error instead of panicking if `rng` fails.
+    ///
+    /// See [`RandomMod::random_mod`] for details about the sampling method.
+    ///
+    /// The default implementation calls [`RandomMod::random_mod`], and therefore never
+    /// returns an error; implementations should override it to propagate failures of `rng`.
+    fn try_random_mod(
+        rng: &mut impl CryptoRngCore,
+        modulus: &NonZero<Self>,
+    ) -> Result<Self, rand_core::Error> {
+        Ok(Self::random_mod(rng, modulus))
+    }
 }
 
 /// Compute `self + rhs mod p`.
@@ -239,7 +265,7 @@ pub trait Encoding: Sized {
 /// Support for optimized squaring
 pub trait Square: Sized
 where
-    for<'a> &'a Self: core::ops::Mul<&'a Self, Output = Self>,
+    for<'a> &'a Self: Mul<&'a Self, Output = Self>,
 {
     /// Computes the same as `self.mul(self)`, but may be more efficient.
     fn square(&self) -> Self {
@@ -269,6 +295,22 @@ pub trait PowBoundedExp<Exponent> {
     fn pow_bounded_exp(&self, exponent: &Exponent, exponent_bits: usize) -> Self;
 }
 
+/// Source of the bits of an exponent, such as [`Uint`][crate::Uint] or
+/// [`BoxedUint`][crate::BoxedUint], for modular exponentiation generic over the
+/// exponent type.
+pub trait Exponent {
+    /// Number of (least significant) bits of the exponent to take into account.
+    ///
+    /// NOTE: this is leaked in the time pattern of the exponentiation.
+    fn bits_precision(&self) -> usize;
+
+    /// Returns the bit at position `index` as a truthy or falsy [`Choice`], or the falsy
+    /// value if `index >= self.bits_precision()`.
+    ///
+    /// This must be constant time with respect to the value of the exponent.
+    fn nth_bit(&self, index: usize) -> Choice;
+}
+
 /// Constant-time inversion.
 pub trait Invert: Sized {
     /// Output of the inversion.
@@ -277,3 +319,42 @@ pub trait Invert: Sized {
     /// Computes the inverse.
     fn invert(&self) -> Self::Output;
 }
+
+/// Elements of a prime field, such as [`Residue`][crate::modular::constant_mod::Residue]s
+/// modulo a prime, for writing algorithms once for any field.
+///
+/// All operations are constant time.
+pub trait Field:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + ConditionallySelectable
+    + Zero
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    /// Integer type used for exponents.
+    type Integer;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Computes `self * self`.
+    fn square(&self) -> Self;
+
+    /// Computes `self + self`.
+    fn double(&self) -> Self;
+
+    /// Computes the multiplicative inverse, or none if `self` is zero
+    /// (or more generally not invertible, if the modulus is not prime).
+    fn invert(&self) -> CtOption<Self>;
+
+    /// Raises to the `exponent` power.
+    fn pow(&self, exponent: &Self::Integer) -> Self;
+}
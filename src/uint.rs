@@ -3,7 +3,7 @@
 #![allow(
     clippy::needless_range_loop,
     clippy::many_single_char_names,
-    clippy::derive_hash_xor_eq
+    clippy::derived_hash_with_manual_eq
 )]
 
 #[macro_use]
@@ -18,16 +18,24 @@ mod bit_not;
 mod bit_or;
 mod bit_xor;
 mod bits;
+#[cfg(feature = "alloc")]
+mod boxed;
 mod cmp;
 mod div;
 pub(crate) mod div_limb;
 mod encoding;
 mod from;
+mod gcd;
+mod glv;
 mod inv_mod;
 mod mul;
 mod mul_mod;
+#[cfg(feature = "alloc")]
+mod naf;
 mod neg;
 mod neg_mod;
+mod pow_mod;
+mod radix;
 mod resize;
 mod shl;
 mod shr;
@@ -38,6 +46,11 @@ mod sub_mod;
 /// Implements modular arithmetic for constant moduli.
 pub mod modular;
 
+pub use cmp::CtOrdering;
+
+#[cfg(feature = "alloc")]
+pub use boxed::BoxedUint;
+
 #[cfg(feature = "generic-array")]
 mod array;
 
@@ -71,6 +84,8 @@ use zeroize::DefaultIsZeroes;
 ///
 /// [RLP]: https://eth.wiki/fundamentals/rlp
 // TODO(tarcieri): make generic around a specified number of bits.
+// NOTE: `Hash` is derived while `PartialEq` is implemented manually in constant time.
+// Both operate on the full `limbs` array, so values which compare equal hash equally.
 #[derive(Copy, Clone, Debug, Hash)]
 pub struct Uint<const LIMBS: usize> {
     /// Inner limb array. Stored from least significant to most significant.
@@ -133,6 +148,36 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         arr
     }
 
+    /// Create a [`Uint`] from an array of [`Word`]s ordered from most significant
+    /// to least significant, i.e. the reverse of [`Uint::from_words`].
+    #[inline]
+    pub const fn from_words_be(arr: [Word; LIMBS]) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+
+        while i < LIMBS {
+            limbs[i] = Limb(arr[LIMBS - 1 - i]);
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Create an array of [`Word`]s ordered from most significant to least
+    /// significant from a [`Uint`], i.e. the reverse of [`Uint::to_words`].
+    #[inline]
+    pub const fn to_words_be(self) -> [Word; LIMBS] {
+        let mut arr = [0; LIMBS];
+        let mut i = 0;
+
+        while i < LIMBS {
+            arr[i] = self.limbs[LIMBS - 1 - i].0;
+            i += 1;
+        }
+
+        arr
+    }
+
     /// Borrow the inner limbs as an array of [`Word`]s.
     pub const fn as_words(&self) -> &[Word; LIMBS] {
         // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
@@ -161,6 +206,27 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         &mut self.limbs
     }
 
+    /// Borrow the significant limbs of this [`Uint`], i.e. with the most significant
+    /// zero limbs removed. Returns an empty slice for [`Uint::ZERO`].
+    ///
+    /// NOTE: the length of the returned slice leaks the magnitude of `self`, so this
+    /// is variable time.
+    pub fn trimmed_limbs(&self) -> &[Limb] {
+        let mut len = LIMBS;
+        while len > 0 && self.limbs[len - 1].0 == 0 {
+            len -= 1;
+        }
+        &self.limbs[..len]
+    }
+
+    /// Borrow the limbs of this [`Uint`] mutably, split into the `n` least
+    /// significant limbs and the remaining most significant limbs.
+    ///
+    /// Panics if `n > LIMBS`.
+    pub fn split_at_limb_mut(&mut self, n: usize) -> (&mut [Limb], &mut [Limb]) {
+        self.limbs.split_at_mut(n)
+    }
+
     /// Convert this [`Uint`] into its inner limbs.
     pub const fn to_limbs(self) -> [Limb; LIMBS] {
         self.limbs
@@ -217,10 +283,11 @@ impl<const LIMBS: usize> Integer for Uint<LIMBS> {
     const LIMBS: usize = Self::LIMBS;
 
     fn is_odd(&self) -> Choice {
-        self.limbs
-            .first()
-            .map(|limb| limb.is_odd())
-            .unwrap_or_else(|| Choice::from(0))
+        self.ct_is_odd().into()
+    }
+
+    fn is_even(&self) -> Choice {
+        self.ct_is_odd().not().into()
     }
 }
 
@@ -241,20 +308,56 @@ impl<const LIMBS: usize> fmt::Display for Uint<LIMBS> {
 
 impl<const LIMBS: usize> fmt::LowerHex for Uint<LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for limb in self.limbs.iter().rev() {
-            fmt::LowerHex::fmt(limb, f)?;
-        }
-        Ok(())
+        fmt_hex(&self.limbs, f, fmt::LowerHex::fmt)
     }
 }
 
 impl<const LIMBS: usize> fmt::UpperHex for Uint<LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for limb in self.limbs.iter().rev() {
-            fmt::UpperHex::fmt(limb, f)?;
+        fmt_hex(&self.limbs, f, fmt::UpperHex::fmt)
+    }
+}
+
+/// Writes all the hex digits of the little endian `limbs` using `fmt_limb`, honoring the
+/// alternate (`0x` prefix), width, fill, alignment and zero padding flags of `f` like the
+/// primitive integer types do.
+fn fmt_hex(
+    limbs: &[Limb],
+    f: &mut fmt::Formatter<'_>,
+    fmt_limb: impl Fn(&Limb, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    use fmt::Write;
+
+    let prefix = if f.alternate() { "0x" } else { "" };
+    let len = prefix.len() + limbs.len() * Limb::BYTES * 2;
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+
+    let (fill, pre, post) = if f.sign_aware_zero_pad() {
+        ('0', 0, 0)
+    } else {
+        match f.align() {
+            Some(fmt::Alignment::Left) => (f.fill(), 0, padding),
+            Some(fmt::Alignment::Center) => (f.fill(), padding / 2, (padding + 1) / 2),
+            Some(fmt::Alignment::Right) | None => (f.fill(), padding, 0),
+        }
+    };
+
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(prefix)?;
+    if f.sign_aware_zero_pad() {
+        for _ in 0..padding {
+            f.write_char('0')?;
         }
-        Ok(())
     }
+    for limb in limbs.iter().rev() {
+        fmt_limb(limb, f)?;
+    }
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
 }
 
 #[cfg(feature = "serde")]
@@ -405,10 +508,10 @@ impl_split! {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Encoding, U128};
+    use crate::{Encoding, Limb, U128};
     use subtle::ConditionallySelectable;
 
-    #[cfg(feature = "serde")]
+    #[cfg(any(feature = "alloc", feature = "serde"))]
     use crate::U64;
 
     #[test]
@@ -433,6 +536,50 @@ mod tests {
         assert_eq!(hex, n.to_string());
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_formatting_flags() {
+        use alloc::format;
+
+        let n = U64::from_u64(0xabcdef);
+        assert_eq!(format!("{:x}", n), "0000000000abcdef");
+        assert_eq!(format!("{:X}", n), "0000000000ABCDEF");
+        assert_eq!(format!("{:#x}", n), "0x0000000000abcdef");
+        assert_eq!(format!("{:#X}", n), "0x0000000000ABCDEF");
+
+        // Zero padding goes between the prefix and the digits
+        assert_eq!(format!("{:020x}", n), "00000000000000abcdef");
+        assert_eq!(format!("{:#020x}", n), "0x000000000000abcdef");
+
+        // Fill and alignment, right aligned by default
+        assert_eq!(format!("{:20x}", n), "    0000000000abcdef");
+        assert_eq!(format!("{:<20x}", n), "0000000000abcdef    ");
+        assert_eq!(format!("{:*^21X}", n), "**0000000000ABCDEF***");
+        assert_eq!(format!("{:>#20x}", n), "  0x0000000000abcdef");
+
+        // Width smaller than the number of digits is ignored
+        assert_eq!(format!("{:4x}", n), "0000000000abcdef");
+        assert_eq!(format!("{:#10}", n), "0x0000000000ABCDEF");
+    }
+
+    #[test]
+    fn words_be() {
+        let n = U128::from_be_hex("00112233445566778899AABBCCDDEEFF");
+        let mut words = n.to_words();
+        words.reverse();
+
+        assert_eq!(n.to_words_be(), words);
+        assert_eq!(U128::from_words_be(words), n);
+        assert_eq!(U128::from_words_be(n.to_words_be()), n);
+
+        const ONE: U128 = U128::from_words_be({
+            let mut words = [0; U128::LIMBS];
+            words[U128::LIMBS - 1] = 1;
+            words
+        });
+        assert_eq!(ONE, U128::ONE);
+    }
+
     #[test]
     fn from_bytes() {
         let a = U128::from_be_hex("AAAAAAAABBBBBBBB0CCCCCCCDDDDDDDD");
@@ -461,6 +608,35 @@ mod tests {
         assert_eq!(b, select_1);
     }
 
+    #[test]
+    fn split_at_limb_mut() {
+        let mut n = U128::from_be_hex("AAAAAAAABBBBBBBBCCCCCCCCDDDDDDDD");
+        let limbs = n.to_limbs();
+
+        let (lo, hi) = n.split_at_limb_mut(1);
+        assert_eq!(lo, &limbs[..1]);
+        assert_eq!(hi, &limbs[1..]);
+
+        lo[0] = Limb::ZERO;
+        hi[0] = Limb::MAX;
+        assert_eq!(n.as_limbs()[0], Limb::ZERO);
+        assert_eq!(n.as_limbs()[1], Limb::MAX);
+
+        let (lo, hi) = n.split_at_limb_mut(U128::LIMBS);
+        assert_eq!(lo.len(), U128::LIMBS);
+        assert!(hi.is_empty());
+    }
+
+    #[test]
+    fn trimmed_limbs() {
+        assert!(U128::ZERO.trimmed_limbs().is_empty());
+        assert_eq!(U128::ONE.trimmed_limbs(), &[Limb::ONE]);
+        assert_eq!(U128::MAX.trimmed_limbs(), U128::MAX.as_limbs());
+
+        let n = U128::from_be_hex("0000000000000000000000000000000F");
+        assert_eq!(n.trimmed_limbs(), &[Limb::from(0xFu8)]);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
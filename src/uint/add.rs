@@ -1,8 +1,8 @@
 //! [`Uint`] addition operations.
 
-use crate::{Checked, CheckedAdd, CtChoice, Limb, Uint, Wrapping, Zero};
+use crate::{Checked, CheckedAdd, CtChoice, Limb, Uint, Word, Wrapping, Zero};
 use core::ops::{Add, AddAssign};
-use subtle::CtOption;
+use subtle::{Choice, CtOption};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `a + b + carry`, returning the result along with the new carry.
@@ -33,10 +33,53 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Perform wrapping addition, discarding overflow.
+    ///
+    /// Unlike the [`Add`] impls this is a `const fn`, so it can be used to derive
+    /// constants at compile time.
     pub const fn wrapping_add(&self, rhs: &Self) -> Self {
         self.adc(rhs, Limb::ZERO).0
     }
 
+    /// Computes `self + rhs` for an `rhs` of a possibly different width, returning the
+    /// result as an integer with `OUT` limbs, which can never overflow.
+    ///
+    /// E.g. adding a [`U64`][crate::U64] to a [`U256`][crate::U256] accumulator can return
+    /// a [`U320`][crate::U320], which holds the carry out of the top limb of the accumulator.
+    ///
+    /// Panics if `OUT` is not greater than both `LIMBS` and `RHS`.
+    pub const fn add_wide<const RHS: usize, const OUT: usize>(&self, rhs: &Uint<RHS>) -> Uint<OUT> {
+        assert!(
+            OUT > LIMBS && OUT > RHS,
+            "the output must be wider than both operands"
+        );
+        self.resize::<OUT>().wrapping_add(&rhs.resize::<OUT>())
+    }
+
+    /// Computes the sum of all of the given `values`, returning the result along
+    /// with the carry limb, i.e. the most significant limb of a result which is
+    /// one limb wider than `Self`.
+    ///
+    /// No carry is lost provided `values` contains at most `2^Limb::BITS` elements.
+    pub fn sum_wide(values: &[Self]) -> (Self, Limb) {
+        let mut sum = Self::ZERO;
+        let mut hi = Limb::ZERO;
+
+        for value in values {
+            let (new_sum, carry) = sum.adc(value, Limb::ZERO);
+            sum = new_sum;
+            hi = hi.wrapping_add(carry);
+        }
+
+        (sum, hi)
+    }
+
+    /// Computes `self + addend` with wrapping if `choice` is truthy, and returns `self`
+    /// unchanged otherwise, in constant time.
+    pub fn conditional_add(&self, addend: &Self, choice: Choice) -> Self {
+        let choice = CtChoice::from_lsb(Word::from(choice.unwrap_u8()));
+        self.conditional_wrapping_add(addend, choice).0
+    }
+
     /// Perform wrapping addition, returning the truthy value as the second element of the tuple
     /// if an overflow has occurred.
     pub(crate) const fn conditional_wrapping_add(
@@ -161,7 +204,8 @@ impl<const LIMBS: usize> AddAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS
 
 #[cfg(test)]
 mod tests {
-    use crate::{CheckedAdd, Limb, U128};
+    use crate::{CheckedAdd, Limb, U128, U256, U320, U64};
+    use subtle::Choice;
 
     #[test]
     fn adc_no_carry() {
@@ -187,6 +231,73 @@ mod tests {
         assert_eq!(U128::MAX.wrapping_add(&U128::ONE), U128::ZERO);
     }
 
+    #[test]
+    fn wrapping_add_const() {
+        const N: U256 =
+            U256::from_be_hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+        const N_PLUS_ONE: U256 = N.wrapping_add(&U256::ONE);
+        assert_eq!(
+            N_PLUS_ONE,
+            U256::from_be_hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632552")
+        );
+    }
+
+    #[test]
+    fn add_wide() {
+        // (2^256 - 1) + (2^64 - 1) = 2^256 + 2^64 - 2
+        let sum: U320 = U256::MAX.add_wide(&U64::MAX);
+        assert_eq!(
+            sum,
+            (U320::ONE << 256).wrapping_add(&U320::from_u64(u64::MAX - 1))
+        );
+        assert_eq!(sum.bits(), 257);
+
+        // The operands can be given in either order
+        let sum: U320 = U64::MAX.add_wide(&U256::MAX);
+        assert_eq!(sum.bits(), 257);
+
+        let sum: U320 = U256::ONE.add_wide(&U64::ONE);
+        assert_eq!(sum, U320::from(2u8));
+    }
+
+    #[test]
+    #[should_panic(expected = "the output must be wider than both operands")]
+    fn add_wide_too_narrow() {
+        let _: U256 = U256::ONE.add_wide(&U64::ONE);
+    }
+
+    #[test]
+    fn sum_wide_empty() {
+        assert_eq!(U128::sum_wide(&[]), (U128::ZERO, Limb::ZERO));
+    }
+
+    #[test]
+    fn sum_wide_no_carry() {
+        let values = [U128::ONE, U128::from(2u8), U128::from(3u8)];
+        assert_eq!(U128::sum_wide(&values), (U128::from(6u8), Limb::ZERO));
+    }
+
+    #[test]
+    fn sum_wide_max() {
+        // n * MAX = (n - 1) * 2^BITS + (2^BITS - n)
+        let values = [U128::MAX; 1000];
+        let (lo, hi) = U128::sum_wide(&values);
+        assert_eq!(hi, Limb::from(999u32));
+        assert_eq!(lo, U128::ZERO.wrapping_sub(&U128::from(1000u32)));
+    }
+
+    #[test]
+    fn conditional_add() {
+        let x = U128::from(5u8);
+        let y = U128::from(7u8);
+        assert_eq!(x.conditional_add(&y, Choice::from(0)), x);
+        assert_eq!(x.conditional_add(&y, Choice::from(1)), U128::from(12u8));
+        assert_eq!(
+            U128::MAX.conditional_add(&y, Choice::from(1)),
+            U128::from(6u8)
+        );
+    }
+
     #[test]
     fn checked_add_ok() {
         let result = U128::ZERO.checked_add(&U128::ONE);
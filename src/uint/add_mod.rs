@@ -1,11 +1,14 @@
 //! [`Uint`] addition modulus operations.
 
-use crate::{AddMod, Limb, Uint};
+use crate::{AddMod, Limb, NonZero, Uint};
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self + rhs mod p` in constant time.
     ///
-    /// Assumes `self + rhs` as unbounded integer is `< 2p`.
+    /// Assumes `self + rhs` as unbounded integer is `< 2p`, which holds when both operands
+    /// are reduced modulo `p`. Otherwise the result is silently wrong (this is checked by a
+    /// debug assertion); use [`Uint::add_mod_reduce`] for operands which may not be reduced.
     pub const fn add_mod(&self, rhs: &Uint<LIMBS>, p: &Uint<LIMBS>) -> Uint<LIMBS> {
         let (w, carry) = self.adc(rhs, Limb::ZERO);
 
@@ -28,9 +31,35 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             i += 1;
         }
 
+        debug_assert!(
+            Uint::ct_lt(&res, p).is_true_vartime(),
+            "operands must be reduced"
+        );
         res
     }
 
+    /// Computes `self + rhs mod p` in constant time, returning none if either operand
+    /// is not reduced, i.e. if `self >= p` or `rhs >= p`.
+    ///
+    /// Unlike [`Uint::add_mod`], whose result is silently wrong for unreduced operands
+    /// in release builds, misuse is always caught. The check is performed in constant time.
+    pub fn checked_add_mod(&self, rhs: &Self, p: &Self) -> CtOption<Self> {
+        let is_reduced = Self::ct_lt(self, p).and(Self::ct_lt(rhs, p));
+
+        // Substitute valid inputs (also covering `p == 0`) so that the debug assertion
+        // of `add_mod` holds
+        let lhs = Self::ct_select(&Self::ZERO, self, is_reduced);
+        let rhs = Self::ct_select(&Self::ZERO, rhs, is_reduced);
+        let p = Self::ct_select(&Self::MAX, p, is_reduced);
+        CtOption::new(lhs.add_mod(&rhs, &p), is_reduced.into())
+    }
+
+    /// Computes `self + rhs mod p` in constant time, first reducing both operands
+    /// modulo `p` so that they can have any value.
+    pub fn add_mod_reduce(&self, rhs: &Self, p: &NonZero<Self>) -> Self {
+        self.rem(p).add_mod(&rhs.rem(p), p)
+    }
+
     /// Computes `self + rhs mod p` in constant time for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -46,6 +75,14 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         let (out, _) = out.sbb(&Uint::from_word(l), Limb::ZERO);
         out
     }
+
+    /// Computes `self + rhs mod 2^k` in constant time.
+    ///
+    /// This is a wrapping addition followed by masking the low `k` bits,
+    /// which avoids the general modular reduction.
+    pub const fn add_mod2k(&self, rhs: &Self, k: usize) -> Self {
+        self.wrapping_add(rhs).rem2k(k)
+    }
 }
 
 impl<const LIMBS: usize> AddMod for Uint<LIMBS> {
@@ -65,6 +102,60 @@ mod tests {
 
     // TODO(tarcieri): additional tests + proptests
 
+    #[test]
+    fn add_mod_reduce() {
+        let p = NonZero::new(U256::from_u64(1000)).unwrap();
+        let a = U256::from_u64(2999);
+        let b = U256::MAX;
+
+        // 2^256 - 1 = 935 (mod 1000)
+        assert_eq!(a.add_mod_reduce(&b, &p), U256::from_u64(934));
+        assert_eq!(a.add_mod_reduce(&b, &p), a.rem(&p).add_mod(&b.rem(&p), &p));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "operands must be reduced")]
+    fn add_mod_unreduced() {
+        let p = U256::from_u64(1000);
+        let _ = U256::from_u64(1500).add_mod(&U256::from_u64(600), &p);
+    }
+
+    #[test]
+    fn checked_add_mod() {
+        let p = U256::from_u64(1000);
+        let (a, b) = (U256::from_u64(600), U256::from_u64(999));
+        assert_eq!(a.checked_add_mod(&b, &p).unwrap(), U256::from_u64(599));
+        assert_eq!(a.checked_add_mod(&b, &p).unwrap(), a.add_mod(&b, &p));
+
+        for (a, b) in [(1000u64, 0u64), (0, 1000), (1500, 600), (600, u64::MAX)] {
+            let (a, b) = (U256::from_u64(a), U256::from_u64(b));
+            assert!(bool::from(a.checked_add_mod(&b, &p).is_none()));
+        }
+
+        // Zero modulus
+        assert!(bool::from(
+            U256::ZERO
+                .checked_add_mod(&U256::ZERO, &U256::ZERO)
+                .is_none()
+        ));
+    }
+
+    #[test]
+    fn add_mod2k() {
+        let a = U256::from_u64(0xffff_ffff_ffff_fff0);
+        let b = U256::from_u8(0x20);
+
+        assert_eq!(a.add_mod2k(&b, 64), U256::from_u8(0x10));
+        assert_eq!(a.add_mod2k(&b, 4), U256::ZERO);
+        assert_eq!(a.add_mod2k(&b, 256), a.wrapping_add(&b));
+        assert_eq!(U256::MAX.add_mod2k(&U256::ONE, 256), U256::ZERO);
+        assert_eq!(
+            U256::MAX.add_mod2k(&U256::MAX, 130),
+            U256::MAX.rem2k(130).wrapping_sub(&U256::ONE)
+        );
+    }
+
     #[test]
     fn add_mod_nist_p256() {
         let a =
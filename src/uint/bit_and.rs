@@ -7,6 +7,9 @@ use subtle::{Choice, CtOption};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes bitwise `a & b`.
+    ///
+    /// In `const` contexts call this as `Uint::bitand(&a, &b)` or use [`Uint::wrapping_and`],
+    /// since `a.bitand(&b)` resolves to the non-`const` [`BitAnd::bitand`].
     #[inline(always)]
     pub const fn bitand(&self, rhs: &Self) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -143,4 +146,15 @@ mod tests {
         let result = U128::MAX.wrapping_and(&U128::ONE);
         assert_eq!(result, U128::ONE);
     }
+
+    #[test]
+    fn bitand_const() {
+        const A: U128 = U128::from_u8(0b1100);
+        const B: U128 = U128::from_u8(0b1010);
+        const RESULT: U128 = U128::bitand(&A, &B);
+        const WRAPPING_RESULT: U128 = A.wrapping_and(&B);
+
+        assert_eq!(RESULT, U128::from_u8(0b1000));
+        assert_eq!(WRAPPING_RESULT, RESULT);
+    }
 }
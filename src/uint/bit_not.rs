@@ -6,6 +6,9 @@ use core::ops::Not;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes bitwise `!a`.
+    ///
+    /// In `const` contexts call this as `Uint::not(&a)`,
+    /// since `a.not()` resolves to the non-`const` [`Not::not`].
     #[inline(always)]
     pub const fn not(&self) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -18,6 +21,17 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         Self { limbs }
     }
+
+    /// Computes the bitwise complement of the `k` least significant bits of `self`,
+    /// setting all the higher bits to zero.
+    ///
+    /// For `self < 2^k`, this is `(2^k - 1) - self`. If `k >= Self::BITS`, this is the same
+    /// as [`Uint::not`].
+    ///
+    /// NOTE: this operation is variable time with respect to `k` *ONLY*.
+    pub const fn not_bits(&self, k: usize) -> Self {
+        Self::not(self).rem2k(k)
+    }
 }
 
 impl<const LIMBS: usize> Not for Uint<LIMBS> {
@@ -39,11 +53,41 @@ impl<const LIMBS: usize> Not for Wrapping<Uint<LIMBS>> {
 
 #[cfg(test)]
 mod tests {
-    use crate::U128;
+    use crate::{U128, U256};
 
     #[test]
     fn bitnot_ok() {
         assert_eq!(U128::ZERO.not(), U128::MAX);
         assert_eq!(U128::MAX.not(), U128::ZERO);
     }
+
+    #[test]
+    fn bitnot_const() {
+        const MASK: U128 = U128::not(&U128::ONE);
+        assert_eq!(MASK, U128::MAX.wrapping_sub(&U128::ONE));
+    }
+
+    #[test]
+    fn not_bits() {
+        let x = U128::from(0b1010u8);
+        assert_eq!(x.not_bits(4), U128::from(0b0101u8));
+        assert_eq!(x.not_bits(6), U128::from(0b11_0101u8));
+        assert_eq!(x.not_bits(0), U128::ZERO);
+        assert_eq!(x.not_bits(128), x.not());
+        assert_eq!(x.not_bits(1000), x.not());
+
+        // Higher bits are cleared
+        assert_eq!(U128::MAX.not_bits(100), U128::ZERO);
+        assert_eq!(U128::ZERO.not_bits(100), U128::MAX.shr_vartime(28));
+    }
+
+    #[test]
+    fn not_bits_is_complement() {
+        let k = 200;
+        let max = U256::ONE.shl_vartime(k).wrapping_sub(&U256::ONE);
+        let x =
+            U256::from_be_hex("00000000000000f3b9cac2fc632551bce6faada7179e84f3b9cac2fc632551ff");
+        assert_eq!(x.not_bits(k), max.wrapping_sub(&x));
+        assert_eq!(x.not_bits(k).not_bits(k), x);
+    }
 }
@@ -6,7 +6,10 @@ use core::ops::{BitOr, BitOrAssign};
 use subtle::{Choice, CtOption};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
-    /// Computes bitwise `a & b`.
+    /// Computes bitwise `a | b`.
+    ///
+    /// In `const` contexts call this as `Uint::bitor(&a, &b)` or use [`Uint::wrapping_or`],
+    /// since `a.bitor(&b)` resolves to the non-`const` [`BitOr::bitor`].
     #[inline(always)]
     pub const fn bitor(&self, rhs: &Self) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -139,4 +142,15 @@ mod tests {
         let result = U128::MAX.wrapping_or(&U128::ONE);
         assert_eq!(result, U128::MAX);
     }
+
+    #[test]
+    fn bitor_const() {
+        const A: U128 = U128::from_u8(0b1100);
+        const B: U128 = U128::from_u8(0b1010);
+        const RESULT: U128 = U128::bitor(&A, &B);
+        const WRAPPING_RESULT: U128 = A.wrapping_or(&B);
+
+        assert_eq!(RESULT, U128::from_u8(0b1110));
+        assert_eq!(WRAPPING_RESULT, RESULT);
+    }
 }
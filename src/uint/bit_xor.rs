@@ -1,12 +1,15 @@
 //! [`Uint`] bitwise xor operations.
 
 use super::Uint;
-use crate::{Limb, Wrapping};
+use crate::{Limb, Word, Wrapping};
 use core::ops::{BitXor, BitXorAssign};
 use subtle::{Choice, CtOption};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes bitwise `a ^ b`.
+    ///
+    /// In `const` contexts call this as `Uint::bitxor(&a, &b)` or use [`Uint::wrapping_xor`],
+    /// since `a.bitxor(&b)` resolves to the non-`const` [`BitXor::bitxor`].
     #[inline(always)]
     pub const fn bitxor(&self, rhs: &Self) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -33,6 +36,21 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         let result = self.bitxor(rhs);
         CtOption::new(result, Choice::from(1))
     }
+
+    /// Returns a [`Limb`] mask which is all ones if `self == rhs` and all zeros otherwise,
+    /// i.e. if `self ^ rhs` is zero, in constant time.
+    ///
+    /// The mask can be ANDed into other limbs directly to build branch-free selections.
+    pub const fn diff_is_zero_mask(&self, rhs: &Self) -> Limb {
+        Limb(Self::ct_eq(self, rhs).if_true(Word::MAX))
+    }
+
+    /// Returns a truthy [`Choice`] if `self == rhs`, i.e. if `self ^ rhs` is zero.
+    ///
+    /// This is equivalent to [`ConstantTimeEq::ct_eq`][subtle::ConstantTimeEq::ct_eq].
+    pub fn xor_mask_eq(&self, rhs: &Self) -> Choice {
+        Self::ct_eq(self, rhs).into()
+    }
 }
 
 impl<const LIMBS: usize> BitXor for Uint<LIMBS> {
@@ -126,7 +144,34 @@ impl<const LIMBS: usize> BitXorAssign<&Wrapping<Uint<LIMBS>>> for Wrapping<Uint<
 
 #[cfg(test)]
 mod tests {
-    use crate::U128;
+    use crate::{Limb, U128};
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn diff_is_zero_mask() {
+        let x = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        assert_eq!(x.diff_is_zero_mask(&x), Limb::MAX);
+        assert_eq!(U128::ZERO.diff_is_zero_mask(&U128::ZERO), Limb::MAX);
+        assert_eq!(x.diff_is_zero_mask(&U128::ZERO), Limb::ZERO);
+        assert_eq!(
+            x.diff_is_zero_mask(&x.bitxor(&U128::ONE.shl_vartime(127))),
+            Limb::ZERO
+        );
+
+        // Branch-free selection of a limb
+        let a = Limb::from(5u32);
+        let b = Limb::from(7u32);
+        let mask = x.diff_is_zero_mask(&x);
+        assert_eq!(a.bitand(mask).bitor(b.bitand(mask.not())), a);
+    }
+
+    #[test]
+    fn xor_mask_eq() {
+        let x = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        for y in [x, U128::ZERO, U128::MAX, x.wrapping_add(&U128::ONE)] {
+            assert_eq!(bool::from(x.xor_mask_eq(&y)), bool::from(x.ct_eq(&y)));
+        }
+    }
 
     #[test]
     fn checked_xor_ok() {
@@ -139,4 +184,15 @@ mod tests {
         let result = U128::ZERO.wrapping_xor(&U128::ONE);
         assert_eq!(result, U128::ONE);
     }
+
+    #[test]
+    fn bitxor_const() {
+        const A: U128 = U128::from_u8(0b1100);
+        const B: U128 = U128::from_u8(0b1010);
+        const RESULT: U128 = U128::bitxor(&A, &B);
+        const WRAPPING_RESULT: U128 = A.wrapping_xor(&B);
+
+        assert_eq!(RESULT, U128::from_u8(0b0110));
+        assert_eq!(WRAPPING_RESULT, RESULT);
+    }
 }
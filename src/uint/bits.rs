@@ -1,4 +1,5 @@
-use crate::{CtChoice, Limb, Uint, Word};
+use crate::{CtChoice, Exponent, Limb, Uint, Word};
+use subtle::Choice;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Returns `true` if the bit at position `index` is set, `false` otherwise.
@@ -23,6 +24,64 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Limb::BITS * (i + 1) - limb.leading_zeros() as usize
     }
 
+    /// Returns the position of the lowest set bit at or above `from`, or `None` if there is none.
+    ///
+    /// Limbs which are zero are skipped as a whole, so iterating over the set bits
+    /// of a sparse value is fast.
+    ///
+    /// NOTE: this operation is variable time.
+    #[allow(trivial_numeric_casts)]
+    pub const fn next_set_bit(&self, from: usize) -> Option<usize> {
+        if from >= Self::BITS {
+            return None;
+        }
+
+        let mut i = from / Limb::BITS;
+        let mut limb = self.limbs[i].0 & (Word::MAX << (from % Limb::BITS));
+
+        loop {
+            if limb != 0 {
+                return Some(i * Limb::BITS + limb.trailing_zeros() as usize);
+            }
+
+            i += 1;
+            if i == LIMBS {
+                return None;
+            }
+            limb = self.limbs[i].0;
+        }
+    }
+
+    /// Returns the position of the highest set bit at or below `from`, or `None` if there is none.
+    ///
+    /// Indices of `Self::BITS` or greater start the scan at the most significant bit.
+    /// Limbs which are zero are skipped as a whole, as in [`Uint::next_set_bit`].
+    ///
+    /// NOTE: this operation is variable time.
+    #[allow(trivial_numeric_casts)]
+    pub const fn prev_set_bit(&self, from: usize) -> Option<usize> {
+        let from = if from >= Self::BITS {
+            Self::BITS - 1
+        } else {
+            from
+        };
+
+        let mut i = from / Limb::BITS;
+        let mut limb = self.limbs[i].0 & (Word::MAX >> (Limb::BITS - 1 - from % Limb::BITS));
+
+        loop {
+            if limb != 0 {
+                return Some((i + 1) * Limb::BITS - 1 - limb.leading_zeros() as usize);
+            }
+
+            if i == 0 {
+                return None;
+            }
+            i -= 1;
+            limb = self.limbs[i].0;
+        }
+    }
+
     /// Calculate the number of leading zeros in the binary representation of this number.
     pub const fn leading_zeros(self) -> usize {
         let limbs = self.as_limbs();
@@ -66,6 +125,15 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Self::BITS - self.leading_zeros()
     }
 
+    /// Returns the base 2 logarithm of `self`, rounded down, i.e. `self.bits() - 1`.
+    ///
+    /// Panics if `self` is zero, like the `ilog2` methods of the primitive integer types.
+    pub const fn ilog2(self) -> u32 {
+        let bits = self.bits();
+        assert!(bits != 0, "argument of integer logarithm must be positive");
+        (bits - 1) as u32
+    }
+
     /// Get the value of the bit at position `index`, as a truthy or falsy `CtChoice`.
     /// Returns the falsy value for indices out of range.
     pub const fn bit(self, index: usize) -> CtChoice {
@@ -86,11 +154,42 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         CtChoice::from_lsb(result >> index_in_limb)
     }
+
+    /// Interprets the low `sign_bit + 1` bits of `self` as a two's complement number
+    /// whose sign is given by the bit at position `sign_bit`; the higher bits are ignored.
+    ///
+    /// Returns the magnitude of that number along with a [`Choice`] which is truthy
+    /// if it is negative.
+    ///
+    /// NOTE: this operation is variable time with respect to `sign_bit` *ONLY*.
+    ///
+    /// Panics if `sign_bit >= Self::BITS`.
+    pub fn as_signed(&self, sign_bit: usize) -> (Self, Choice) {
+        assert!(sign_bit < Self::BITS, "sign bit is out of range");
+
+        let sign_value = Self::ONE.shl_vartime(sign_bit);
+        let low = self.bitand(&sign_value.wrapping_sub(&Self::ONE));
+        let is_negative = self.bit(sign_bit);
+
+        // A negative value is `low - 2^sign_bit`
+        let magnitude = Self::ct_select(&low, &sign_value.wrapping_sub(&low), is_negative);
+        (magnitude, is_negative.into())
+    }
+}
+
+impl<const LIMBS: usize> Exponent for Uint<LIMBS> {
+    fn bits_precision(&self) -> usize {
+        Self::BITS
+    }
+
+    fn nth_bit(&self, index: usize) -> Choice {
+        self.bit(index).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::U256;
+    use crate::{U128, U256};
 
     fn uint_with_bits_at(positions: &[usize]) -> U256 {
         let mut result = U256::ZERO;
@@ -112,6 +211,45 @@ mod tests {
         assert!(!u.bit_vartime(260));
     }
 
+    #[test]
+    fn next_set_bit() {
+        let positions = [16, 48, 112, 127, 255];
+        let u = uint_with_bits_at(&positions);
+
+        assert_eq!(u.next_set_bit(0), Some(16));
+        assert_eq!(u.next_set_bit(16), Some(16));
+        assert_eq!(u.next_set_bit(17), Some(48));
+        assert_eq!(u.next_set_bit(128), Some(255));
+        assert_eq!(u.next_set_bit(256), None);
+        assert_eq!(U256::ZERO.next_set_bit(0), None);
+        assert_eq!(uint_with_bits_at(&[3]).next_set_bit(4), None);
+
+        // Iterating over the set bits yields exactly the given positions
+        let mut found = [0; 5];
+        let mut count = 0;
+        let mut next = u.next_set_bit(0);
+        while let Some(pos) = next {
+            found[count] = pos;
+            count += 1;
+            next = u.next_set_bit(pos + 1);
+        }
+        assert_eq!(found, positions);
+    }
+
+    #[test]
+    fn prev_set_bit() {
+        let u = uint_with_bits_at(&[0, 16, 48, 112, 127, 255]);
+
+        assert_eq!(u.prev_set_bit(255), Some(255));
+        assert_eq!(u.prev_set_bit(254), Some(127));
+        assert_eq!(u.prev_set_bit(111), Some(48));
+        assert_eq!(u.prev_set_bit(15), Some(0));
+        assert_eq!(u.prev_set_bit(0), Some(0));
+        assert_eq!(u.prev_set_bit(usize::MAX), Some(255));
+        assert_eq!(uint_with_bits_at(&[200]).prev_set_bit(199), None);
+        assert_eq!(U256::ZERO.prev_set_bit(255), None);
+    }
+
     #[test]
     fn bit() {
         let u = uint_with_bits_at(&[16, 48, 112, 127, 255]);
@@ -142,6 +280,22 @@ mod tests {
         assert_eq!(u.leading_zeros() as u32, 256);
     }
 
+    #[test]
+    fn ilog2() {
+        assert_eq!(U256::ONE.ilog2(), 0);
+        assert_eq!(U256::from_u8(2).ilog2(), 1);
+        assert_eq!(U256::from_u8(3).ilog2(), 1);
+        assert_eq!(U256::from_u8(4).ilog2(), 2);
+        assert_eq!(uint_with_bits_at(&[16, 48, 112]).ilog2(), 112);
+        assert_eq!(U256::MAX.ilog2(), 255u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "argument of integer logarithm must be positive")]
+    fn ilog2_zero() {
+        let _ = U256::ZERO.ilog2();
+    }
+
     #[test]
     fn trailing_zeros() {
         let u = uint_with_bits_at(&[16, 79, 150]);
@@ -159,4 +313,38 @@ mod tests {
         let u = U256::ZERO;
         assert_eq!(u.trailing_zeros() as u32, 256);
     }
+
+    #[test]
+    fn as_signed() {
+        let signed = |x: u128, sign_bit| {
+            let (magnitude, is_negative) = U128::from_u128(x).as_signed(sign_bit);
+            (magnitude, bool::from(is_negative))
+        };
+
+        // Sign bit clear
+        assert_eq!(signed(0x7f, 7), (U128::from_u8(0x7f), false));
+        assert_eq!(signed(0, 7), (U128::ZERO, false));
+        assert_eq!(signed(0x1234, 15), (U128::from_u16(0x1234), false));
+
+        // Sign bit set
+        assert_eq!(signed(0xff, 7), (U128::ONE, true));
+        assert_eq!(signed(0x80, 7), (U128::from_u8(0x80), true));
+        assert_eq!(signed(0xfffe, 15), (U128::from_u8(2), true));
+        assert_eq!(signed(1, 0), (U128::ONE, true));
+
+        // Bits above the sign bit are ignored
+        assert_eq!(signed(0xab7f, 7), (U128::from_u8(0x7f), false));
+        assert_eq!(signed(0xabff, 7), (U128::ONE, true));
+
+        // Full width
+        assert_eq!(signed(u128::MAX, 127), (U128::ONE, true));
+        assert_eq!(signed(1 << 127, 127), (U128::ONE << 127, true));
+        assert_eq!(signed(u128::MAX >> 1, 127), (U128::MAX >> 1, false));
+    }
+
+    #[test]
+    #[should_panic(expected = "sign bit is out of range")]
+    fn as_signed_out_of_range() {
+        let _ = U128::ONE.as_signed(128);
+    }
 }
@@ -0,0 +1,483 @@
+//! Heap-allocated big unsigned integers.
+
+mod add;
+mod add_mod;
+mod cmp;
+mod div;
+mod encoding;
+mod mul;
+mod mul_mod;
+mod shl;
+mod shr;
+mod sub;
+mod sub_mod;
+
+use crate::{Exponent, Limb, Uint, Word, Zero};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+use subtle::{Choice, ConditionallySelectable};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Heap-allocated big unsigned integer whose width is chosen at runtime.
+///
+/// Unlike [`Uint`], the number of limbs is not part of the type. All the values
+/// taking part in an operation are expected to have the same width, unless noted
+/// otherwise; operations panic if that is not the case.
+#[derive(Clone, Default)]
+pub struct BoxedUint {
+    /// Inner limb vector. Stored from least significant to most significant.
+    limbs: Box<[Limb]>,
+}
+
+impl BoxedUint {
+    /// Get the value `0` with the given number of limbs.
+    pub fn zero_with_limbs(nlimbs: usize) -> Self {
+        vec![Limb::ZERO; nlimbs].into()
+    }
+
+    /// Get the value `1` with the given number of limbs.
+    ///
+    /// Panics if `nlimbs` is zero.
+    pub fn one_with_limbs(nlimbs: usize) -> Self {
+        let mut ret = Self::zero_with_limbs(nlimbs);
+        ret.limbs[0] = Limb::ONE;
+        ret
+    }
+
+    /// Get the maximum value representable with the given number of limbs.
+    pub fn max_with_limbs(nlimbs: usize) -> Self {
+        vec![Limb::MAX; nlimbs].into()
+    }
+
+    /// Create a [`BoxedUint`] from a slice of [`Word`]s, least significant first.
+    pub fn from_words(words: &[Word]) -> Self {
+        words.iter().copied().map(Limb).collect::<Vec<_>>().into()
+    }
+
+    /// Create a [`BoxedUint`] with the given number of limbs from a single [`Word`].
+    ///
+    /// Panics if `nlimbs` is zero.
+    pub fn from_word(n: Word, nlimbs: usize) -> Self {
+        let mut ret = Self::zero_with_limbs(nlimbs);
+        ret.limbs[0] = Limb(n);
+        ret
+    }
+
+    /// Get the number of limbs in this [`BoxedUint`].
+    pub fn nlimbs(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Get the precision of this [`BoxedUint`] in bits.
+    pub fn bits_precision(&self) -> usize {
+        self.limbs.len() * Limb::BITS
+    }
+
+    /// Borrow the limbs of this [`BoxedUint`].
+    pub fn as_limbs(&self) -> &[Limb] {
+        &self.limbs
+    }
+
+    /// Borrow the limbs of this [`BoxedUint`] mutably.
+    pub fn as_limbs_mut(&mut self) -> &mut [Limb] {
+        &mut self.limbs
+    }
+
+    /// Convert this [`BoxedUint`] into its inner limbs.
+    pub fn to_limbs(&self) -> Box<[Limb]> {
+        self.limbs.clone()
+    }
+
+    /// Is this [`BoxedUint`] equal to zero?
+    pub fn is_zero(&self) -> Choice {
+        self.limbs
+            .iter()
+            .fold(Choice::from(1), |acc, limb| acc & limb.is_zero())
+    }
+
+    /// Is this [`BoxedUint`] an odd number?
+    pub fn is_odd(&self) -> Choice {
+        self.limbs
+            .first()
+            .map(|limb| limb.is_odd())
+            .unwrap_or_else(|| Choice::from(0))
+    }
+
+    /// Returns `true` if the bit at position `index` is set, `false` otherwise.
+    pub fn bit_vartime(&self, index: usize) -> bool {
+        if index >= self.bits_precision() {
+            false
+        } else {
+            (self.limbs[index / Limb::BITS].0 >> (index % Limb::BITS)) & 1 == 1
+        }
+    }
+
+    /// Calculate the number of bits needed to represent this number.
+    pub fn bits_vartime(&self) -> usize {
+        match self.limbs.iter().rposition(|limb| limb.0 != 0) {
+            Some(i) => Limb::BITS * (i + 1) - self.limbs[i].0.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Return a copy of `self` with the given number of limbs, truncating or zero-extending
+    /// the most significant limbs as needed.
+    pub fn resize(&self, nlimbs: usize) -> Self {
+        let mut ret = Self::zero_with_limbs(nlimbs);
+        let n = nlimbs.min(self.nlimbs());
+        ret.limbs[..n].copy_from_slice(&self.limbs[..n]);
+        ret
+    }
+
+    /// Convert to a [`Uint`], returning `None` if the value does not fit in `LIMBS` limbs.
+    pub fn to_uint<const LIMBS: usize>(&self) -> Option<Uint<LIMBS>> {
+        if self.limbs.iter().skip(LIMBS).any(|limb| limb.0 != 0) {
+            return None;
+        }
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let n = LIMBS.min(self.nlimbs());
+        limbs[..n].copy_from_slice(&self.limbs[..n]);
+        Some(Uint::new(limbs))
+    }
+
+    /// Conditionally select `a` or `b` according to `choice`:
+    /// returns `a` if `choice` is falsy, and `b` otherwise.
+    ///
+    /// Panics if `a` and `b` have different widths.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        a.assert_same_width(b);
+        a.limbs
+            .iter()
+            .zip(b.limbs.iter())
+            .map(|(a, b)| Limb::conditional_select(a, b, choice))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Conditionally assign `other` to `self` according to `choice`.
+    ///
+    /// Panics if `self` and `other` have different widths.
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        self.assert_same_width(other);
+        for (a, b) in self.limbs.iter_mut().zip(other.limbs.iter()) {
+            a.conditional_assign(b, choice);
+        }
+    }
+
+    /// Panics if `self` and `rhs` have different widths.
+    fn assert_same_width(&self, rhs: &Self) {
+        assert_eq!(
+            self.nlimbs(),
+            rhs.nlimbs(),
+            "BoxedUint operands have different widths"
+        );
+    }
+}
+
+impl Exponent for BoxedUint {
+    fn bits_precision(&self) -> usize {
+        self.bits_precision()
+    }
+
+    /// NOTE: this is variable time with respect to `index` only.
+    fn nth_bit(&self, index: usize) -> Choice {
+        if index >= self.bits_precision() {
+            Choice::from(0)
+        } else {
+            Choice::from(((self.limbs[index / Limb::BITS].0 >> (index % Limb::BITS)) & 1) as u8)
+        }
+    }
+}
+
+impl AsRef<[Limb]> for BoxedUint {
+    fn as_ref(&self) -> &[Limb] {
+        self.as_limbs()
+    }
+}
+
+impl AsMut<[Limb]> for BoxedUint {
+    fn as_mut(&mut self) -> &mut [Limb] {
+        self.as_limbs_mut()
+    }
+}
+
+impl From<Box<[Limb]>> for BoxedUint {
+    fn from(limbs: Box<[Limb]>) -> Self {
+        Self { limbs }
+    }
+}
+
+impl From<Vec<Limb>> for BoxedUint {
+    fn from(limbs: Vec<Limb>) -> Self {
+        limbs.into_boxed_slice().into()
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BoxedUint {
+    fn from(uint: Uint<LIMBS>) -> Self {
+        Vec::from(uint.to_limbs()).into()
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for BoxedUint {
+    fn from(uint: &Uint<LIMBS>) -> Self {
+        Vec::from(uint.to_limbs()).into()
+    }
+}
+
+impl fmt::Debug for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BoxedUint(0x{self:X})")
+    }
+}
+
+impl fmt::Display for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_hex(&self.limbs, f, fmt::LowerHex::fmt)
+    }
+}
+
+impl fmt::UpperHex for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_hex(&self.limbs, f, fmt::UpperHex::fmt)
+    }
+}
+
+/// Serialized as a tuple of the precision in bits and the little endian bytes, so that the
+/// width is preserved when deserializing.
+///
+/// The encoding does not depend on the size of [`Limb`]: on deserialization, the number of
+/// limbs is the precision rounded up to a whole number of limbs of the target platform.
+#[cfg(feature = "serde")]
+impl Serialize for BoxedUint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&(self.bits_precision() as u64))?;
+        tuple.serialize_element(&HexOrBin(self.to_le_bytes().into_vec()))?;
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BoxedUint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BoxedUintVisitor;
+
+        impl<'de> Visitor<'de> for BoxedUintVisitor {
+            type Value = BoxedUint;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "a precision in bits followed by little endian bytes"
+                )
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<BoxedUint, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let bits: u64 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let HexOrBin(bytes) = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                let expected_bits = u64::try_from(bytes.len())
+                    .ok()
+                    .and_then(|len| len.checked_mul(8));
+                if expected_bits != Some(bits) {
+                    return Err(de::Error::invalid_length(bytes.len(), &self));
+                }
+
+                // Rounds the width up to a whole number of limbs
+                Ok(BoxedUint::from_le_slice(&bytes))
+            }
+        }
+
+        deserializer.deserialize_tuple(2, BoxedUintVisitor)
+    }
+}
+
+/// Byte buffer encoded as hex for human readable formats and as raw bytes otherwise.
+#[cfg(feature = "serde")]
+struct HexOrBin(Vec<u8>);
+
+#[cfg(feature = "serde")]
+impl Serialize for HexOrBin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serdect::slice::serialize_hex_lower_or_bin(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for HexOrBin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serdect::slice::deserialize_hex_or_bin_vec(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for BoxedUint {
+    fn zeroize(&mut self) {
+        self.limbs.zeroize();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::BoxedUint;
+    use crate::{Limb, U128, U256};
+
+    #[test]
+    fn from_uint_roundtrip() {
+        let x =
+            U256::from_be_hex("00112233445566778899aabbccddeeff0123456789abcdeffedcba9876543210");
+        let boxed = BoxedUint::from(x);
+        assert_eq!(boxed.nlimbs(), U256::LIMBS);
+        assert_eq!(boxed.bits_precision(), 256);
+        assert_eq!(boxed.to_uint::<{ U256::LIMBS }>(), Some(x));
+        assert_eq!(boxed.to_uint::<{ U128::LIMBS }>(), None);
+        assert_eq!(BoxedUint::from(&x), boxed);
+    }
+
+    #[test]
+    fn to_uint_widens() {
+        let boxed = BoxedUint::from(U128::MAX).resize(U256::LIMBS);
+        assert_eq!(
+            boxed.to_uint::<{ U128::LIMBS }>(),
+            Some(U128::MAX),
+            "high zero limbs are dropped"
+        );
+        assert_eq!(boxed.to_uint::<{ U256::LIMBS }>(), Some(U128::MAX.resize()));
+    }
+
+    #[test]
+    fn bits() {
+        assert_eq!(BoxedUint::zero_with_limbs(3).bits_vartime(), 0);
+        assert_eq!(BoxedUint::one_with_limbs(3).bits_vartime(), 1);
+        assert_eq!(BoxedUint::max_with_limbs(3).bits_vartime(), 3 * Limb::BITS);
+        assert!(BoxedUint::from_word(4, 2).bit_vartime(2));
+        assert!(!BoxedUint::from_word(4, 2).bit_vartime(1));
+    }
+
+    #[test]
+    fn is_zero_and_is_odd() {
+        assert!(bool::from(BoxedUint::zero_with_limbs(2).is_zero()));
+        assert!(!bool::from(BoxedUint::one_with_limbs(2).is_zero()));
+        assert!(bool::from(BoxedUint::one_with_limbs(2).is_odd()));
+        assert!(!bool::from(BoxedUint::from_word(2, 2).is_odd()));
+    }
+
+    #[test]
+    fn hex_formatting_flags() {
+        use alloc::format;
+
+        let x = U128::from_u64(0xabcdef);
+        let boxed = BoxedUint::from(x);
+        assert_eq!(format!("{:#x}", boxed), format!("{:#x}", x));
+        assert_eq!(format!("{:40x}", boxed), format!("{:40x}", x));
+        assert_eq!(
+            format!("{:40x}", boxed),
+            "        00000000000000000000000000abcdef"
+        );
+        assert_eq!(format!("{:#040X}", boxed), format!("{:#040X}", x));
+        assert_eq!(format!("{:*<#36x}", boxed), format!("{:*<#36x}", x));
+        assert_eq!(format!("{}", boxed), format!("{}", x));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        for nlimbs in [1, 2, 3, 5, 8] {
+            let n = BoxedUint::max_with_limbs(nlimbs)
+                .wrapping_sub(&BoxedUint::from_word(0x0011_2233, nlimbs));
+
+            let serialized = bincode::serialize(&n).unwrap();
+            let deserialized: BoxedUint = bincode::deserialize(&serialized).unwrap();
+            assert_eq!(deserialized, n);
+            assert_eq!(deserialized.nlimbs(), nlimbs);
+
+            let serialized = bincode::serialize(&BoxedUint::zero_with_limbs(nlimbs)).unwrap();
+            let deserialized: BoxedUint = bincode::deserialize_from(serialized.as_slice()).unwrap();
+            assert_eq!(
+                deserialized.nlimbs(),
+                nlimbs,
+                "leading zero limbs are preserved"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_length_mismatch() {
+        let serialized = bincode::serialize(&(3u64, &[0u8; Limb::BYTES][..])).unwrap();
+        assert!(bincode::deserialize::<BoxedUint>(&serialized).is_err());
+
+        let serialized = bincode::serialize(&(128u64, &[0u8; 8][..])).unwrap();
+        assert!(bincode::deserialize::<BoxedUint>(&serialized).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_wire_format() {
+        use alloc::vec::Vec;
+        use hex_literal::hex;
+
+        // bincode encodes the precision as a `u64`, then the byte length as a `u64`
+        fn wire(bits: u64, bytes: &[u8]) -> Vec<u8> {
+            let mut wire = Vec::new();
+            wire.extend_from_slice(&bits.to_le_bytes());
+            wire.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+            wire.extend_from_slice(bytes);
+            wire
+        }
+
+        let bytes = hex!("efcdab8967452301 1032547698badcfe");
+        let n = BoxedUint::from(U128::from_le_slice(&bytes));
+        assert_eq!(bincode::serialize(&n).unwrap(), wire(128, &bytes));
+        let deserialized: BoxedUint = bincode::deserialize(&wire(128, &bytes)).unwrap();
+        assert_eq!(deserialized, n);
+        assert_eq!(deserialized.bits_precision(), 128);
+
+        // Three 32-bit limbs, as serialized on a 32-bit platform, are rounded up to whole
+        // limbs on this one
+        let bytes = hex!("0100000002000000 03000000");
+        let deserialized: BoxedUint = bincode::deserialize(&wire(96, &bytes)).unwrap();
+        assert_eq!(deserialized.nlimbs(), (96 + Limb::BITS - 1) / Limb::BITS);
+        assert_eq!(
+            deserialized,
+            BoxedUint::from(U128::from_be_hex("00000000000000030000000200000001"))
+                .resize(deserialized.nlimbs())
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! [`BoxedUint`] addition operations.
+
+use crate::{BoxedUint, CheckedAdd, Limb, Wrapping, Zero};
+use core::ops::{Add, AddAssign};
+use subtle::CtOption;
+
+impl BoxedUint {
+    /// Computes `a + b + carry`, returning the result along with the new carry.
+    ///
+    /// Panics if `self` and `rhs` have different widths.
+    pub fn adc(&self, rhs: &Self, mut carry: Limb) -> (Self, Limb) {
+        self.assert_same_width(rhs);
+        let mut result = self.clone();
+
+        for (a, b) in result.limbs.iter_mut().zip(rhs.limbs.iter()) {
+            let (sum, c) = a.adc(*b, carry);
+            *a = sum;
+            carry = c;
+        }
+
+        (result, carry)
+    }
+
+    /// Perform wrapping addition, discarding overflow.
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.adc(rhs, Limb::ZERO).0
+    }
+}
+
+impl CheckedAdd<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        let (result, carry) = self.adc(rhs, Limb::ZERO);
+        CtOption::new(result, carry.is_zero())
+    }
+}
+
+impl Add for Wrapping<BoxedUint> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl Add<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn add(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl Add<Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn add(self, rhs: Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl Add<&Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn add(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl AddAssign for Wrapping<BoxedUint> {
+    fn add_assign(&mut self, other: Self) {
+        *self = &*self + other;
+    }
+}
+
+impl AddAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    fn add_assign(&mut self, other: &Self) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, CheckedAdd, Limb, Wrapping};
+
+    #[test]
+    fn adc_no_carry() {
+        let (res, carry) =
+            BoxedUint::zero_with_limbs(2).adc(&BoxedUint::one_with_limbs(2), Limb::ZERO);
+        assert_eq!(res, BoxedUint::one_with_limbs(2));
+        assert_eq!(carry, Limb::ZERO);
+    }
+
+    #[test]
+    fn adc_with_carry() {
+        let (res, carry) =
+            BoxedUint::max_with_limbs(2).adc(&BoxedUint::one_with_limbs(2), Limb::ZERO);
+        assert_eq!(res, BoxedUint::zero_with_limbs(2));
+        assert_eq!(carry, Limb::ONE);
+    }
+
+    #[test]
+    fn checked_add() {
+        let one = BoxedUint::one_with_limbs(3);
+        assert_eq!(
+            BoxedUint::zero_with_limbs(3).checked_add(&one).unwrap(),
+            one
+        );
+        assert!(bool::from(
+            BoxedUint::max_with_limbs(3).checked_add(&one).is_none()
+        ));
+    }
+
+    #[test]
+    fn wrapping_add_op() {
+        let sum = Wrapping(BoxedUint::max_with_limbs(2)) + Wrapping(BoxedUint::from_word(2, 2));
+        assert_eq!(sum.0, BoxedUint::one_with_limbs(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "BoxedUint operands have different widths")]
+    fn different_widths() {
+        let _ = BoxedUint::one_with_limbs(2).wrapping_add(&BoxedUint::one_with_limbs(3));
+    }
+}
@@ -0,0 +1,59 @@
+//! [`BoxedUint`] addition modulus operations.
+
+use crate::{AddMod, BoxedUint, Limb};
+use alloc::vec::Vec;
+
+impl BoxedUint {
+    /// Computes `self + rhs mod p` in constant time.
+    ///
+    /// Assumes `self + rhs` as unbounded integer is `< 2p`.
+    pub fn add_mod(&self, rhs: &Self, p: &Self) -> Self {
+        let (w, carry) = self.adc(rhs, Limb::ZERO);
+
+        // Attempt to subtract the modulus, to ensure the result is in the field.
+        let (w, borrow) = w.sbb(p, Limb::ZERO);
+        let (_, borrow) = carry.sbb(Limb::ZERO, borrow);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the
+        // modulus.
+        let mask: BoxedUint = p
+            .limbs
+            .iter()
+            .map(|limb| *limb & borrow)
+            .collect::<Vec<_>>()
+            .into();
+        w.wrapping_add(&mask)
+    }
+}
+
+impl AddMod for BoxedUint {
+    type Output = Self;
+
+    fn add_mod(&self, rhs: &Self, p: &Self) -> Self {
+        debug_assert!(self < p);
+        debug_assert!(rhs < p);
+        self.add_mod(rhs, p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, U256};
+
+    #[test]
+    fn add_mod_matches_uint() {
+        let p =
+            U256::from_be_hex("928334a4e4be0843ec225a4c9c61df34bdc7a81513e4b6f76f2bfa3148e2e1b5");
+        let a =
+            U256::from_be_hex("8d16e171674b4e6d8529edba4593802bf30b8cb161dd30aa8e550d41380007c2");
+        let b =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+
+        let actual = BoxedUint::from(a).add_mod(&BoxedUint::from(b), &BoxedUint::from(p));
+        assert_eq!(actual, BoxedUint::from(a.add_mod(&b, &p)));
+
+        let actual = BoxedUint::from(b).add_mod(&BoxedUint::from(b), &BoxedUint::from(p));
+        assert_eq!(actual, BoxedUint::from(b.add_mod(&b, &p)));
+    }
+}
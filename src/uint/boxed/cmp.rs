@@ -0,0 +1,175 @@
+//! [`BoxedUint`] comparisons.
+//!
+//! By default these are all constant-time and use the `subtle` crate.
+//! Values of different widths are compared as if the narrower one was zero-extended.
+
+use crate::{BoxedUint, CtChoice, Limb};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+use subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+impl BoxedUint {
+    /// Returns the limb-wise pairs of `self` and `rhs`, zero-extending the narrower of them.
+    fn zip_extended<'a>(
+        &'a self,
+        rhs: &'a Self,
+    ) -> impl DoubleEndedIterator<Item = (Limb, Limb)> + 'a {
+        let n = self.nlimbs().max(rhs.nlimbs());
+        (0..n).map(move |i| {
+            (
+                self.limbs.get(i).copied().unwrap_or(Limb::ZERO),
+                rhs.limbs.get(i).copied().unwrap_or(Limb::ZERO),
+            )
+        })
+    }
+
+    /// Returns the truthy value if `lhs < rhs` and the falsy value otherwise.
+    fn ct_lt(lhs: &Self, rhs: &Self) -> CtChoice {
+        let borrow = lhs
+            .zip_extended(rhs)
+            .fold(Limb::ZERO, |borrow, (a, b)| a.sbb(b, borrow).1);
+        CtChoice::from_mask(borrow.0)
+    }
+
+    /// Compares `self` and `rhs` in variable-time.
+    pub fn cmp_vartime(&self, rhs: &Self) -> Ordering {
+        self.zip_extended(rhs)
+            .rev()
+            .map(|(a, b)| a.0.cmp(&b.0))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl ConstantTimeEq for BoxedUint {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let acc = self
+            .zip_extended(other)
+            .fold(0, |acc, (a, b)| acc | (a.0 ^ b.0));
+
+        // acc == 0 if and only if self == other
+        Limb(acc).ct_is_nonzero().not().into()
+    }
+}
+
+impl ConstantTimeGreater for BoxedUint {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        BoxedUint::ct_lt(other, self).into()
+    }
+}
+
+impl ConstantTimeLess for BoxedUint {
+    #[inline]
+    fn ct_lt(&self, other: &Self) -> Choice {
+        BoxedUint::ct_lt(self, other).into()
+    }
+}
+
+impl Eq for BoxedUint {}
+
+impl Ord for BoxedUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let is_lt = self.ct_lt(other);
+        let is_eq = self.ct_eq(other);
+
+        if is_lt.into() {
+            Ordering::Less
+        } else if is_eq.into() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl PartialOrd for BoxedUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BoxedUint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Hash for BoxedUint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Skip the most significant zero limbs, so that values which compare equal
+        // across different widths also hash equally
+        let len = self
+            .limbs
+            .iter()
+            .rposition(|limb| limb.0 != 0)
+            .map_or(0, |i| i + 1);
+        self.limbs[..len].hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::hash, BoxedUint};
+    use core::cmp::Ordering;
+    use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+    #[test]
+    fn hash_consistent_with_eq_across_widths() {
+        assert_eq!(
+            hash(&BoxedUint::zero_with_limbs(2)),
+            hash(&BoxedUint::zero_with_limbs(4))
+        );
+
+        let a = BoxedUint::from_word(0xdead_beef, 1);
+        for nlimbs in 2..5 {
+            let b = a.resize(nlimbs);
+            assert_eq!(a, b);
+            assert_eq!(hash(&a), hash(&b));
+        }
+
+        assert_ne!(
+            hash(&BoxedUint::one_with_limbs(2)),
+            hash(&BoxedUint::zero_with_limbs(2))
+        );
+    }
+
+    #[test]
+    fn ct_eq() {
+        let a = BoxedUint::zero_with_limbs(2);
+        let b = BoxedUint::max_with_limbs(2);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(bool::from(a.ct_eq(&BoxedUint::zero_with_limbs(4))));
+    }
+
+    #[test]
+    fn ct_gt_lt() {
+        let a = BoxedUint::one_with_limbs(2);
+        let b = BoxedUint::max_with_limbs(2);
+
+        assert!(bool::from(b.ct_gt(&a)));
+        assert!(!bool::from(a.ct_gt(&b)));
+        assert!(!bool::from(a.ct_gt(&a)));
+        assert!(bool::from(a.ct_lt(&b)));
+        assert!(!bool::from(b.ct_lt(&a)));
+        assert!(!bool::from(a.ct_lt(&a)));
+    }
+
+    #[test]
+    fn cmp_different_widths() {
+        let a = BoxedUint::max_with_limbs(1);
+        let b = BoxedUint::from_word(1, 1).resize(3);
+        let c = BoxedUint::max_with_limbs(1).resize(2);
+
+        assert_eq!(a.cmp(&b), Ordering::Greater);
+        assert_eq!(b.cmp(&a), Ordering::Less);
+        assert_eq!(a.cmp(&c), Ordering::Equal);
+        assert_eq!(a.cmp_vartime(&b), Ordering::Greater);
+        assert_eq!(a.cmp_vartime(&c), Ordering::Equal);
+    }
+}
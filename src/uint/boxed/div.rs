@@ -0,0 +1,135 @@
+//! [`BoxedUint`] division operations.
+
+use crate::{BoxedUint, Limb};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+impl BoxedUint {
+    /// Computes `self` / `rhs`, returning the quotient, the remainder,
+    /// and the truthy value if `rhs != 0`.
+    ///
+    /// This is variable only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    fn ct_div_rem(&self, rhs: &Self) -> (Self, Self, Choice) {
+        self.assert_same_width(rhs);
+
+        let mb = rhs.bits_vartime();
+        let mut bd = self.bits_precision() - mb;
+        let mut rem = self.clone();
+        let mut quo = Self::zero_with_limbs(self.nlimbs());
+        let mut c = rhs.shl_vartime(bd);
+        let one = Self::one_with_limbs(self.nlimbs());
+
+        loop {
+            let (r, borrow) = rem.sbb(&c, Limb::ZERO);
+            let no_borrow = borrow.ct_eq(&Limb::ZERO);
+            rem.conditional_assign(&r, no_borrow);
+            quo.conditional_assign(&quo.wrapping_add(&one), no_borrow);
+            if bd == 0 {
+                break;
+            }
+            bd -= 1;
+            c = c.shr_vartime(1);
+            quo = quo.shl_vartime(1);
+        }
+
+        let is_some = Choice::from((mb != 0) as u8);
+        (quo, rem, is_some)
+    }
+
+    /// Computes `self` / `rhs`, returning the quotient and the remainder.
+    ///
+    /// Panics if `rhs == 0` or if `self` and `rhs` have different widths.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r, is_some) = self.ct_div_rem(rhs);
+        assert!(bool::from(is_some), "divide by zero");
+        (q, r)
+    }
+
+    /// Wrapped division is just normal division i.e. `self` / `rhs`.
+    ///
+    /// Panics if `rhs == 0`.
+    pub fn wrapping_div(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Wrapped (modular) remainder calculation is just `self` % `rhs`.
+    ///
+    /// Panics if `rhs == 0`.
+    pub fn wrapping_rem(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    /// Perform checked division, returning a [`CtOption`] which `is_some`
+    /// only if the rhs != 0.
+    pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
+        let (q, _r, is_some) = self.ct_div_rem(rhs);
+        CtOption::new(q, is_some)
+    }
+
+    /// Perform checked reduction, returning a [`CtOption`] which `is_some`
+    /// only if the rhs != 0.
+    pub fn checked_rem(&self, rhs: &Self) -> CtOption<Self> {
+        let (_q, r, is_some) = self.ct_div_rem(rhs);
+        CtOption::new(r, is_some)
+    }
+
+    /// Computes `self % modulus` where `self` may be wider than `modulus`,
+    /// returning a result as wide as `modulus`.
+    ///
+    /// Panics if `modulus == 0`.
+    pub(crate) fn rem_wide(&self, modulus: &Self) -> Self {
+        let nlimbs = self.nlimbs().max(modulus.nlimbs());
+        self.resize(nlimbs)
+            .wrapping_rem(&modulus.resize(nlimbs))
+            .resize(modulus.nlimbs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, U256};
+
+    #[test]
+    fn div_rem() {
+        let x = BoxedUint::from_word(100, 2);
+        let (q, r) = x.div_rem(&BoxedUint::from_word(7, 2));
+        assert_eq!(q, BoxedUint::from_word(14, 2));
+        assert_eq!(r, BoxedUint::from_word(2, 2));
+
+        let max = BoxedUint::max_with_limbs(3);
+        let (q, r) = max.div_rem(&max);
+        assert_eq!(q, BoxedUint::one_with_limbs(3));
+        assert_eq!(r, BoxedUint::zero_with_limbs(3));
+    }
+
+    #[test]
+    fn div_rem_matches_uint() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let y =
+            U256::from_be_hex("00000000000000000000000000000000e36c1342c2c58972fffffffffffffff1");
+        let (q, r) = BoxedUint::from(x).div_rem(&BoxedUint::from(y));
+        assert_eq!(q, BoxedUint::from(x.wrapping_div(&y)));
+        assert_eq!(r, BoxedUint::from(x.wrapping_rem(&y)));
+    }
+
+    #[test]
+    fn checked_div_rem_zero() {
+        let x = BoxedUint::from_word(100, 2);
+        let zero = BoxedUint::zero_with_limbs(2);
+        assert!(bool::from(x.checked_div(&zero).is_none()));
+        assert!(bool::from(x.checked_rem(&zero).is_none()));
+        assert_eq!(
+            x.checked_rem(&BoxedUint::from_word(7, 2)).unwrap(),
+            BoxedUint::from_word(2, 2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn div_rem_zero() {
+        let _ = BoxedUint::one_with_limbs(2).div_rem(&BoxedUint::zero_with_limbs(2));
+    }
+}
@@ -0,0 +1,78 @@
+//! [`BoxedUint`] encoding operations.
+
+use crate::{BoxedUint, Limb, Word};
+use alloc::{boxed::Box, vec::Vec};
+
+impl BoxedUint {
+    /// Create a new [`BoxedUint`] from the provided big endian bytes.
+    ///
+    /// The result has the smallest number of limbs which can hold `bytes.len()` bytes.
+    pub fn from_be_slice(bytes: &[u8]) -> Self {
+        let mut le_bytes = bytes.to_vec();
+        le_bytes.reverse();
+        Self::from_le_slice(&le_bytes)
+    }
+
+    /// Create a new [`BoxedUint`] from the provided little endian bytes.
+    ///
+    /// The result has the smallest number of limbs which can hold `bytes.len()` bytes.
+    pub fn from_le_slice(bytes: &[u8]) -> Self {
+        bytes
+            .chunks(Limb::BYTES)
+            .map(|chunk| {
+                let mut buf = [0u8; Limb::BYTES];
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Limb(Word::from_le_bytes(buf))
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Serialize this [`BoxedUint`] as big-endian bytes.
+    pub fn to_be_bytes(&self) -> Box<[u8]> {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Serialize this [`BoxedUint`] as little-endian bytes.
+    pub fn to_le_bytes(&self) -> Box<[u8]> {
+        self.limbs
+            .iter()
+            .flat_map(|limb| limb.0.to_le_bytes())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Limb, U128};
+    use hex_literal::hex;
+
+    #[test]
+    fn from_be_slice() {
+        let bytes = hex!("00112233445566778899aabbccddeeff");
+        let n = BoxedUint::from_be_slice(&bytes);
+        assert_eq!(n, BoxedUint::from(U128::from_be_slice(&bytes)));
+        assert_eq!(n.to_be_bytes().as_ref(), bytes);
+    }
+
+    #[test]
+    fn from_le_slice() {
+        let bytes = hex!("ffeeddccbbaa99887766554433221100");
+        let n = BoxedUint::from_le_slice(&bytes);
+        assert_eq!(n, BoxedUint::from(U128::from_le_slice(&bytes)));
+        assert_eq!(n.to_le_bytes().as_ref(), bytes);
+    }
+
+    #[test]
+    fn from_be_slice_partial_limb() {
+        let n = BoxedUint::from_be_slice(&hex!("010203"));
+        assert_eq!(n.nlimbs(), 1);
+        assert_eq!(n, BoxedUint::from_word(0x010203, 1));
+
+        let n = BoxedUint::from_be_slice(&[0xff; Limb::BYTES + 1]);
+        assert_eq!(n.nlimbs(), 2);
+        assert_eq!(n.bits_vartime(), 8 * (Limb::BYTES + 1));
+    }
+}
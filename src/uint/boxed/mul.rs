@@ -0,0 +1,146 @@
+//! [`BoxedUint`] multiplication operations.
+
+use crate::{BoxedUint, CheckedMul, Limb, Wrapping, Zero};
+use core::ops::{Mul, MulAssign};
+use subtle::CtOption;
+
+impl BoxedUint {
+    /// Compute the full product of `self` and `rhs`.
+    ///
+    /// The operands may have different widths: the result is as wide as both of them combined.
+    pub fn mul_wide(&self, rhs: &Self) -> Self {
+        let mut product = Self::zero_with_limbs(self.nlimbs() + rhs.nlimbs());
+
+        // Schoolbook multiplication.
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = Limb::ZERO;
+
+            for (j, b) in rhs.limbs.iter().enumerate() {
+                let (n, c) = product.limbs[i + j].mac(*a, *b, carry);
+                product.limbs[i + j] = n;
+                carry = c;
+            }
+
+            product.limbs[i + rhs.nlimbs()] = carry;
+        }
+
+        product
+    }
+
+    /// Perform wrapping multiplication, discarding overflow.
+    ///
+    /// Panics if `self` and `rhs` have different widths.
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.assert_same_width(rhs);
+        self.mul_wide(rhs).resize(self.nlimbs())
+    }
+
+    /// Square self, returning a "wide" result twice the width of `self`.
+    pub fn square_wide(&self) -> Self {
+        self.mul_wide(self)
+    }
+}
+
+impl CheckedMul<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    fn checked_mul(&self, rhs: &BoxedUint) -> CtOption<Self> {
+        self.assert_same_width(rhs);
+        let product = self.mul_wide(rhs);
+        let overflow = product.limbs[self.nlimbs()..]
+            .iter()
+            .fold(Limb::ZERO, |acc, limb| acc | *limb);
+        CtOption::new(product.resize(self.nlimbs()), overflow.is_zero())
+    }
+}
+
+impl Mul for Wrapping<BoxedUint> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl Mul<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn mul(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl Mul<Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn mul(self, rhs: Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl Mul<&Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn mul(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl MulAssign for Wrapping<BoxedUint> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = &*self * other;
+    }
+}
+
+impl MulAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    fn mul_assign(&mut self, other: &Self) {
+        *self = &*self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, CheckedMul, Limb, Wrapping, U128, U256};
+
+    #[test]
+    fn mul_wide_matches_uint() {
+        let a = U128::from_be_hex("0123456789abcdeffedcba9876543210");
+        let b = U128::from_be_hex("ffffffffffffffff0000000000000001");
+        let (lo, hi) = a.mul_wide(&b);
+        let expected: U256 = hi.concat(&lo);
+
+        let product = BoxedUint::from(a).mul_wide(&BoxedUint::from(b));
+        assert_eq!(product, BoxedUint::from(expected));
+        assert_eq!(product.nlimbs(), 2 * U128::LIMBS);
+    }
+
+    #[test]
+    fn mul_wide_different_widths() {
+        let a = BoxedUint::max_with_limbs(1);
+        let b = BoxedUint::max_with_limbs(2);
+
+        // (2^w - 1)(2^2w - 1) = 2^3w - 2^2w - 2^w + 1
+        let product = a.mul_wide(&b);
+        assert_eq!(product.nlimbs(), 3);
+        assert_eq!(
+            product,
+            BoxedUint::from_words(&[1, Limb::MAX.0, Limb::MAX.0 - 1])
+        );
+    }
+
+    #[test]
+    fn checked_mul() {
+        let two = BoxedUint::from_word(2, 2);
+        assert_eq!(two.checked_mul(&two).unwrap(), BoxedUint::from_word(4, 2));
+        assert!(bool::from(
+            BoxedUint::max_with_limbs(2).checked_mul(&two).is_none()
+        ));
+    }
+
+    #[test]
+    fn wrapping_mul_op() {
+        let product =
+            Wrapping(BoxedUint::max_with_limbs(2)) * Wrapping(BoxedUint::max_with_limbs(2));
+        assert_eq!(product.0, BoxedUint::one_with_limbs(2));
+    }
+}
@@ -0,0 +1,204 @@
+//! [`BoxedUint`] multiplication modulus operations.
+
+use crate::{BoxedUint, Limb, Uint, WideWord, Word};
+use subtle::ConstantTimeEq;
+
+impl BoxedUint {
+    /// Computes `self * rhs mod p`.
+    ///
+    /// This is variable only with respect to `p`.
+    ///
+    /// Panics if `p == 0`.
+    pub fn mul_mod(&self, rhs: &Self, p: &Self) -> Self {
+        self.mul_wide(rhs).rem_wide(p)
+    }
+
+    /// Computes `self^exponent mod p` using Montgomery multiplication.
+    ///
+    /// The exponent may have any width; the time taken depends on its width,
+    /// but not on its value.
+    ///
+    /// Panics if `p` is even, or if `self` and `p` have different widths.
+    pub fn pow_mod(&self, exponent: &Self, p: &Self) -> Self {
+        assert!(bool::from(p.is_odd()), "modulus must be odd");
+        self.assert_same_width(p);
+
+        let params = MontgomeryParams::new(p);
+        let x = params.convert(self);
+        params.retrieve(&params.pow(&x, exponent))
+    }
+}
+
+/// Parameters for Montgomery multiplication modulo an odd [`BoxedUint`].
+struct MontgomeryParams {
+    modulus: BoxedUint,
+    // R mod modulus, i.e. 1 in Montgomery form
+    r: BoxedUint,
+    // R^2 mod modulus, used to move into Montgomery form
+    r2: BoxedUint,
+    // The lowest limb of -(modulus^-1) mod R
+    mod_neg_inv: Limb,
+}
+
+impl MontgomeryParams {
+    fn new(modulus: &BoxedUint) -> Self {
+        let nlimbs = modulus.nlimbs();
+        let r = BoxedUint::max_with_limbs(nlimbs)
+            .wrapping_rem(modulus)
+            .wrapping_add(&BoxedUint::one_with_limbs(nlimbs));
+        let r2 = r.square_wide().rem_wide(modulus);
+        let inv = Uint::<1>::from_word(modulus.limbs[0].0).inv_mod2k(Word::BITS as usize);
+        let mod_neg_inv = Limb(Word::MIN.wrapping_sub(inv.limbs[0].0));
+
+        Self {
+            modulus: modulus.clone(),
+            r,
+            r2,
+            mod_neg_inv,
+        }
+    }
+
+    fn convert(&self, x: &BoxedUint) -> BoxedUint {
+        self.mul(x, &self.r2)
+    }
+
+    fn retrieve(&self, x: &BoxedUint) -> BoxedUint {
+        self.reduce(&x.resize(2 * x.nlimbs()))
+    }
+
+    fn mul(&self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
+        self.reduce(&a.mul_wide(b))
+    }
+
+    /// Modular exponentiation with a fixed window, as in `pow_montgomery_form`.
+    fn pow(&self, x: &BoxedUint, exponent: &BoxedUint) -> BoxedUint {
+        const WINDOW: usize = 4;
+        const WINDOW_MASK: Word = (1 << WINDOW) - 1;
+
+        // powers[i] contains x^i
+        let mut powers = alloc::vec![self.r.clone(), x.clone()];
+        for i in 2..(1 << WINDOW) {
+            powers.push(self.mul(&powers[i - 1], x));
+        }
+
+        let mut z = self.r.clone(); // 1 in Montgomery form
+
+        for limb in exponent.limbs.iter().rev() {
+            for window_num in (0..Limb::BITS / WINDOW).rev() {
+                for _ in 0..WINDOW {
+                    z = self.mul(&z, &z);
+                }
+
+                // Constant-time lookup in the array of powers
+                let idx = (limb.0 >> (window_num * WINDOW)) & WINDOW_MASK;
+                let mut power = powers[0].clone();
+                for (i, candidate) in powers.iter().enumerate().skip(1) {
+                    power.conditional_assign(candidate, Limb(i as Word).ct_eq(&Limb(idx)));
+                }
+
+                z = self.mul(&z, &power);
+            }
+        }
+
+        z
+    }
+
+    /// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+    ///
+    /// Same as `montgomery_reduction`, with `lower_upper` given as a single double-width value.
+    fn reduce(&self, lower_upper: &BoxedUint) -> BoxedUint {
+        let nlimbs = self.modulus.nlimbs();
+        let modulus = &self.modulus.limbs;
+        let mut t = lower_upper.limbs.clone();
+
+        let mut meta_carry: WideWord = 0;
+
+        for i in 0..nlimbs {
+            let u = (t[i].0.wrapping_mul(self.mod_neg_inv.0)) as WideWord;
+
+            let mut carry = 0;
+            for j in 0..nlimbs {
+                let new_limb = (u * modulus[j].0 as WideWord)
+                    .wrapping_add(t[i + j].0 as WideWord)
+                    .wrapping_add(carry);
+                carry = new_limb >> Word::BITS;
+                t[i + j] = Limb(new_limb as Word);
+            }
+
+            let new_sum = (t[i + nlimbs].0 as WideWord)
+                .wrapping_add(carry)
+                .wrapping_add(meta_carry);
+            meta_carry = new_sum >> Word::BITS;
+            t[i + nlimbs] = Limb(new_sum as Word);
+        }
+
+        // Division is simply taking the upper half of the limbs
+        // Final reduction (at this point, the value is at most 2 * modulus)
+        let upper = BoxedUint::from(t[nlimbs..].to_vec());
+        let (reduced, borrow) = upper.sbb(&self.modulus, Limb::ZERO);
+        let (_, borrow) = Limb(meta_carry as Word).sbb(Limb::ZERO, borrow);
+        BoxedUint::conditional_select(&reduced, &upper, borrow.ct_eq(&Limb::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        BoxedUint, U256,
+    };
+
+    const P: U256 =
+        U256::from_be_hex("9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B");
+
+    #[test]
+    fn mul_mod_matches_residue() {
+        let a =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let b =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let params = DynResidueParams::new(&P);
+        let expected = (DynResidue::new(&a, params) * DynResidue::new(&b, params)).retrieve();
+
+        let actual = BoxedUint::from(a).mul_mod(&BoxedUint::from(b), &BoxedUint::from(P));
+        assert_eq!(actual, BoxedUint::from(expected));
+    }
+
+    #[test]
+    fn pow_mod_matches_residue() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let params = DynResidueParams::new(&P);
+        let expected = DynResidue::new(&base, params).pow(&exponent).retrieve();
+
+        let actual = BoxedUint::from(base).pow_mod(&BoxedUint::from(exponent), &BoxedUint::from(P));
+        assert_eq!(actual, BoxedUint::from(expected));
+    }
+
+    #[test]
+    fn pow_mod_small_exponent() {
+        let p = BoxedUint::from_word(101, 3);
+        let x = BoxedUint::from_word(7, 3);
+
+        // 7^5 = 16807 = 166 * 101 + 41
+        assert_eq!(
+            x.pow_mod(&BoxedUint::from_word(5, 1), &p),
+            BoxedUint::from_word(41, 3)
+        );
+        assert_eq!(
+            x.pow_mod(&BoxedUint::zero_with_limbs(1), &p),
+            BoxedUint::one_with_limbs(3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be odd")]
+    fn pow_mod_even_modulus() {
+        let _ = BoxedUint::one_with_limbs(1)
+            .pow_mod(&BoxedUint::one_with_limbs(1), &BoxedUint::from_word(10, 1));
+    }
+}
@@ -0,0 +1,58 @@
+//! [`BoxedUint`] bitwise left shift operations.
+
+use crate::{BoxedUint, Limb};
+
+impl BoxedUint {
+    /// Computes `self << shift`, discarding the bits shifted out.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub fn shl_vartime(&self, n: usize) -> Self {
+        let nlimbs = self.nlimbs();
+        let mut ret = Self::zero_with_limbs(nlimbs);
+
+        if n >= self.bits_precision() {
+            return ret;
+        }
+
+        let shift_num = n / Limb::BITS;
+        let rem = n % Limb::BITS;
+
+        for i in (shift_num..nlimbs).rev() {
+            let mut limb = self.limbs[i - shift_num].0 << rem;
+            if rem > 0 && i > shift_num {
+                limb |= self.limbs[i - shift_num - 1].0 >> (Limb::BITS - rem);
+            }
+            ret.limbs[i] = Limb(limb);
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Limb};
+
+    #[test]
+    fn shl_vartime() {
+        let one = BoxedUint::one_with_limbs(2);
+
+        assert_eq!(one.shl_vartime(0), one);
+        assert_eq!(one.shl_vartime(1), BoxedUint::from_word(2, 2));
+        assert_eq!(
+            one.shl_vartime(Limb::BITS + 3),
+            BoxedUint::from_words(&[0, 8])
+        );
+        assert_eq!(
+            one.shl_vartime(2 * Limb::BITS),
+            BoxedUint::zero_with_limbs(2)
+        );
+        assert_eq!(
+            BoxedUint::max_with_limbs(2).shl_vartime(Limb::BITS),
+            BoxedUint::from_words(&[0, Limb::MAX.0])
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! [`BoxedUint`] bitwise right shift operations.
+
+use crate::{BoxedUint, Limb};
+
+impl BoxedUint {
+    /// Computes `self >> shift`.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub fn shr_vartime(&self, n: usize) -> Self {
+        let nlimbs = self.nlimbs();
+        let mut ret = Self::zero_with_limbs(nlimbs);
+
+        if n >= self.bits_precision() {
+            return ret;
+        }
+
+        let shift_num = n / Limb::BITS;
+        let rem = n % Limb::BITS;
+
+        for i in 0..nlimbs - shift_num {
+            let mut limb = self.limbs[i + shift_num].0 >> rem;
+            if rem > 0 && i + shift_num + 1 < nlimbs {
+                limb |= self.limbs[i + shift_num + 1].0 << (Limb::BITS - rem);
+            }
+            ret.limbs[i] = Limb(limb);
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Limb};
+
+    #[test]
+    fn shr_vartime() {
+        let x = BoxedUint::from_words(&[0, 8]);
+
+        assert_eq!(x.shr_vartime(0), x);
+        assert_eq!(x.shr_vartime(1), BoxedUint::from_words(&[0, 4]));
+        assert_eq!(x.shr_vartime(Limb::BITS + 3), BoxedUint::one_with_limbs(2));
+        assert_eq!(x.shr_vartime(2 * Limb::BITS), BoxedUint::zero_with_limbs(2));
+        assert_eq!(
+            BoxedUint::max_with_limbs(2).shr_vartime(Limb::BITS),
+            BoxedUint::from_words(&[Limb::MAX.0, 0])
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! [`BoxedUint`] subtraction operations.
+
+use crate::{BoxedUint, CheckedSub, Limb, Wrapping, Zero};
+use core::ops::{Sub, SubAssign};
+use subtle::CtOption;
+
+impl BoxedUint {
+    /// Computes `a - (b + borrow)`, returning the result along with the new borrow.
+    ///
+    /// Panics if `self` and `rhs` have different widths.
+    pub fn sbb(&self, rhs: &Self, mut borrow: Limb) -> (Self, Limb) {
+        self.assert_same_width(rhs);
+        let mut result = self.clone();
+
+        for (a, b) in result.limbs.iter_mut().zip(rhs.limbs.iter()) {
+            let (diff, b) = a.sbb(*b, borrow);
+            *a = diff;
+            borrow = b;
+        }
+
+        (result, borrow)
+    }
+
+    /// Perform wrapping subtraction, discarding underflow and wrapping around
+    /// the boundary of the type.
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.sbb(rhs, Limb::ZERO).0
+    }
+}
+
+impl CheckedSub<&BoxedUint> for BoxedUint {
+    type Output = Self;
+
+    fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        let (result, underflow) = self.sbb(rhs, Limb::ZERO);
+        CtOption::new(result, underflow.is_zero())
+    }
+}
+
+impl Sub for Wrapping<BoxedUint> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl Sub<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn sub(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl Sub<Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn sub(self, rhs: Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl Sub<&Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn sub(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl SubAssign for Wrapping<BoxedUint> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = &*self - other;
+    }
+}
+
+impl SubAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    fn sub_assign(&mut self, other: &Self) {
+        *self = &*self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, CheckedSub, Limb, Wrapping};
+
+    #[test]
+    fn sbb_no_borrow() {
+        let (res, borrow) =
+            BoxedUint::one_with_limbs(2).sbb(&BoxedUint::one_with_limbs(2), Limb::ZERO);
+        assert_eq!(res, BoxedUint::zero_with_limbs(2));
+        assert_eq!(borrow, Limb::ZERO);
+    }
+
+    #[test]
+    fn sbb_with_borrow() {
+        let (res, borrow) =
+            BoxedUint::zero_with_limbs(2).sbb(&BoxedUint::one_with_limbs(2), Limb::ZERO);
+        assert_eq!(res, BoxedUint::max_with_limbs(2));
+        assert_eq!(borrow, Limb::MAX);
+    }
+
+    #[test]
+    fn checked_sub() {
+        let one = BoxedUint::one_with_limbs(3);
+        assert_eq!(
+            BoxedUint::from_word(3, 3).checked_sub(&one).unwrap(),
+            BoxedUint::from_word(2, 3)
+        );
+        assert!(bool::from(
+            BoxedUint::zero_with_limbs(3).checked_sub(&one).is_none()
+        ));
+    }
+
+    #[test]
+    fn wrapping_sub_op() {
+        let diff = Wrapping(BoxedUint::zero_with_limbs(2)) - Wrapping(BoxedUint::one_with_limbs(2));
+        assert_eq!(diff.0, BoxedUint::max_with_limbs(2));
+    }
+}
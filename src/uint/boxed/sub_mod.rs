@@ -0,0 +1,88 @@
+//! [`BoxedUint`] subtraction and negation modulus operations.
+
+use crate::{BoxedUint, Limb, NegMod, SubMod};
+use alloc::vec::Vec;
+
+impl BoxedUint {
+    /// Computes `self - rhs mod p` in constant time.
+    ///
+    /// Assumes `self - rhs` as unbounded signed integer is in `[-p, p)`.
+    pub fn sub_mod(&self, rhs: &Self, p: &Self) -> Self {
+        let (out, borrow) = self.sbb(rhs, Limb::ZERO);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+        let mask: BoxedUint = p
+            .limbs
+            .iter()
+            .map(|limb| *limb & borrow)
+            .collect::<Vec<_>>()
+            .into();
+        out.wrapping_add(&mask)
+    }
+
+    /// Computes `-self mod p` in constant time.
+    ///
+    /// Assumes `self` is in `[0, p)`.
+    pub fn neg_mod(&self, p: &Self) -> Self {
+        Self::zero_with_limbs(self.nlimbs()).sub_mod(self, p)
+    }
+}
+
+impl SubMod for BoxedUint {
+    type Output = Self;
+
+    fn sub_mod(&self, rhs: &Self, p: &Self) -> Self {
+        debug_assert!(self < p);
+        debug_assert!(rhs < p);
+        self.sub_mod(rhs, p)
+    }
+}
+
+impl NegMod for BoxedUint {
+    type Output = Self;
+
+    fn neg_mod(&self, p: &Self) -> Self {
+        debug_assert!(self < p);
+        self.neg_mod(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, U256};
+
+    #[test]
+    fn sub_mod_matches_uint() {
+        let p =
+            U256::from_be_hex("928334a4e4be0843ec225a4c9c61df34bdc7a81513e4b6f76f2bfa3148e2e1b5");
+        let a =
+            U256::from_be_hex("8d16e171674b4e6d8529edba4593802bf30b8cb161dd30aa8e550d41380007c2");
+        let b =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+
+        let (a_boxed, b_boxed, p_boxed) =
+            (BoxedUint::from(a), BoxedUint::from(b), BoxedUint::from(p));
+        assert_eq!(
+            a_boxed.sub_mod(&b_boxed, &p_boxed),
+            BoxedUint::from(a.sub_mod(&b, &p))
+        );
+        assert_eq!(
+            b_boxed.sub_mod(&a_boxed, &p_boxed),
+            BoxedUint::from(b.sub_mod(&a, &p))
+        );
+    }
+
+    #[test]
+    fn neg_mod() {
+        let p = BoxedUint::from_word(101, 2);
+        assert_eq!(
+            BoxedUint::from_word(1, 2).neg_mod(&p),
+            BoxedUint::from_word(100, 2)
+        );
+        assert_eq!(
+            BoxedUint::zero_with_limbs(2).neg_mod(&p),
+            BoxedUint::zero_with_limbs(2)
+        );
+    }
+}
@@ -3,9 +3,51 @@
 //! By default these are all constant-time and use the `subtle` crate.
 
 use super::Uint;
-use crate::{CtChoice, Limb};
+use crate::{CtChoice, Limb, U128};
 use core::cmp::Ordering;
-use subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
+};
+
+/// The result of a constant-time comparison of two [`Uint`]s, see [`Uint::ct_cmp`].
+///
+/// The ordering is encoded as two [`Choice`]s, so it can be consumed without branching,
+/// e.g. by [`ConditionallySelectable::conditional_swap`] in a sorting network.
+#[derive(Copy, Clone, Debug)]
+pub struct CtOrdering {
+    is_lt: Choice,
+    is_eq: Choice,
+}
+
+impl CtOrdering {
+    /// Returns a truthy [`Choice`] if the left-hand side is less than the right-hand side.
+    pub fn is_lt(&self) -> Choice {
+        self.is_lt
+    }
+
+    /// Returns a truthy [`Choice`] if both sides are equal.
+    pub fn is_eq(&self) -> Choice {
+        self.is_eq
+    }
+
+    /// Returns a truthy [`Choice`] if the left-hand side is greater than the right-hand side.
+    pub fn is_gt(&self) -> Choice {
+        !(self.is_lt | self.is_eq)
+    }
+}
+
+impl From<CtOrdering> for Ordering {
+    fn from(ordering: CtOrdering) -> Ordering {
+        if ordering.is_lt.into() {
+            Ordering::Less
+        } else if ordering.is_eq.into() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Return `b` if `c` is truthy, otherwise return `a`.
@@ -30,6 +72,64 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (new_a, new_b)
     }
 
+    /// Swap the limbs of `self` and `other` in-place in constant time if
+    /// `choice` is truthy, otherwise leave both unchanged.
+    ///
+    /// Equivalent to [`ConditionallySelectable::conditional_swap`],
+    /// but operates limb-by-limb on the mutable limb arrays.
+    #[inline]
+    pub fn cswap_with(&mut self, other: &mut Self, choice: Choice) {
+        for (a, b) in self
+            .as_limbs_mut()
+            .iter_mut()
+            .zip(other.as_limbs_mut().iter_mut())
+        {
+            Limb::conditional_swap(a, b, choice);
+        }
+    }
+
+    /// Compares `a` and `b` in constant time, without revealing which of
+    /// less, equal or greater the result is.
+    ///
+    /// Combined with [`ConditionallySelectable::conditional_swap`], this is enough
+    /// to build constant-time sorting networks over secret values.
+    pub fn ct_cmp(a: &Self, b: &Self) -> CtOrdering {
+        let (diff, borrow) = a.sbb(b, Limb::ZERO);
+
+        CtOrdering {
+            is_lt: CtChoice::from_mask(borrow.0).into(),
+            is_eq: diff.ct_is_nonzero().not().into(),
+        }
+    }
+
+    /// Compares the fixed-width big-endian byte encodings of `self` and `other`
+    /// lexicographically, e.g. for byte-ordered contexts such as sorted Merkle leaves.
+    ///
+    /// Since both encodings are `Self::BYTES` long, this always agrees with the numeric
+    /// order given by [`Ord::cmp`], so it can be passed to `sort_by` without converting
+    /// to bytes first.
+    pub fn cmp_be_bytes(&self, other: &Self) -> Ordering {
+        Ord::cmp(self, other)
+    }
+
+    /// Returns the smallest nonzero element of `values`, or none if all of them are zero
+    /// (including when `values` is empty).
+    ///
+    /// The whole slice is always scanned, so this only leaks the length of `values`.
+    pub fn ct_min_nonzero(values: &[Self]) -> CtOption<Self> {
+        let mut min = Self::ZERO;
+        let mut found = CtChoice::FALSE;
+
+        for value in values {
+            let is_nonzero = value.ct_is_nonzero();
+            let is_smaller = found.not().or(Self::ct_lt(value, &min));
+            min = Self::ct_select(&min, value, is_nonzero.and(is_smaller));
+            found = found.or(is_nonzero);
+        }
+
+        CtOption::new(min, found.into())
+    }
+
     /// Returns the truthy value if `self`!=0 or the falsy value otherwise.
     #[inline]
     pub(crate) const fn ct_is_nonzero(&self) -> CtChoice {
@@ -44,7 +144,10 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Returns the truthy value if `self` is odd or the falsy value otherwise.
     pub(crate) const fn ct_is_odd(&self) -> CtChoice {
-        CtChoice::from_lsb(self.limbs[0].0 & 1)
+        match self.limbs.first() {
+            Some(limb) => CtChoice::from_lsb(limb.0 & 1),
+            None => CtChoice::from_lsb(0),
+        }
     }
 
     /// Returns the truthy value if `self == rhs` or the falsy value otherwise.
@@ -78,6 +181,35 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         let (_res, borrow) = rhs.sbb(lhs, Limb::ZERO);
         CtChoice::from_mask(borrow.0)
     }
+
+    /// Compares `self` against `rhs` zero-extended to the wider of the two widths.
+    fn cmp_u128(&self, rhs: u128) -> Ordering {
+        let rhs = U128::from_u128(rhs);
+        let n = if LIMBS > U128::LIMBS {
+            LIMBS
+        } else {
+            U128::LIMBS
+        };
+
+        let mut borrow = Limb::ZERO;
+        let mut acc = 0;
+
+        for i in 0..n {
+            let a = self.limbs.get(i).copied().unwrap_or(Limb::ZERO);
+            let b = rhs.limbs.get(i).copied().unwrap_or(Limb::ZERO);
+            let (diff, new_borrow) = a.sbb(b, borrow);
+            acc |= diff.0;
+            borrow = new_borrow;
+        }
+
+        if CtChoice::from_mask(borrow.0).is_true_vartime() {
+            Ordering::Less
+        } else if acc == 0 {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
 }
 
 impl<const LIMBS: usize> ConstantTimeEq for Uint<LIMBS> {
@@ -130,10 +262,60 @@ impl<const LIMBS: usize> PartialEq for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> PartialEq<u64> for Uint<LIMBS> {
+    fn eq(&self, other: &u64) -> bool {
+        self.cmp_u128((*other).into()) == Ordering::Equal
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd<u64> for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        Some(self.cmp_u128((*other).into()))
+    }
+}
+
+impl<const LIMBS: usize> PartialEq<u128> for Uint<LIMBS> {
+    fn eq(&self, other: &u128) -> bool {
+        self.cmp_u128(*other) == Ordering::Equal
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd<u128> for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &u128) -> Option<Ordering> {
+        Some(self.cmp_u128(*other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Integer, Zero, U128};
-    use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+    use crate::{test_utils::hash, Encoding, Integer, Uint, Zero, U128, U256, U64};
+    use core::cmp::Ordering;
+    use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+    #[test]
+    fn hash_consistent_with_eq() {
+        let x =
+            U256::from_be_hex("00000000000000000000000000000000000000000000000000000000deadbeef");
+
+        // The same value obtained in different ways
+        let values = [
+            x,
+            U256::from_u64(0xdead_beef),
+            U256::from_u32(0xdead_beef),
+            U256::MAX.wrapping_sub(&U256::MAX).wrapping_add(&x),
+            U256::conditional_select(&U256::ZERO, &x, 1.into()),
+            U256::from_u64(0xdead_beef_0000).shr_vartime(16),
+        ];
+
+        for a in &values {
+            for b in &values {
+                assert_eq!(a, b);
+                assert_eq!(hash(a), hash(b));
+            }
+        }
+
+        assert_ne!(hash(&x), hash(&x.wrapping_add(&U256::ONE)));
+    }
 
     #[test]
     fn is_zero() {
@@ -149,6 +331,123 @@ mod tests {
         assert!(bool::from(U128::MAX.is_odd()));
     }
 
+    #[test]
+    fn is_even() {
+        assert!(bool::from(U128::ZERO.is_even()));
+        assert!(!bool::from(U128::ONE.is_even()));
+        assert!(!bool::from(U128::MAX.is_even()));
+    }
+
+    #[test]
+    fn parity_through_integer_trait() {
+        fn parity<T: Integer>(x: &T) -> (bool, bool) {
+            (x.is_odd().into(), x.is_even().into())
+        }
+
+        assert_eq!(parity(&U64::from(4u8)), (false, true));
+        assert_eq!(parity(&U128::from(7u8)), (true, false));
+        assert_eq!(parity(&U256::MAX), (true, false));
+        assert_eq!(parity(&U256::ZERO), (false, true));
+    }
+
+    #[test]
+    fn cswap_with() {
+        let a = U128::from_be_hex("00002222444466668888AAAACCCCEEEE");
+        let b = U128::from_be_hex("11113333555577779999BBBBDDDDFFFF");
+
+        let mut x = a;
+        let mut y = b;
+        x.cswap_with(&mut y, 0.into());
+        assert_eq!(x, a);
+        assert_eq!(y, b);
+
+        x.cswap_with(&mut y, 1.into());
+        assert_eq!(x, b);
+        assert_eq!(y, a);
+
+        x.cswap_with(&mut y, 1.into());
+        assert_eq!(x, a);
+        assert_eq!(y, b);
+    }
+
+    #[test]
+    fn ct_cmp() {
+        let values = [U128::ZERO, U128::ONE, U128::ONE << 64, U128::MAX];
+
+        for a in &values {
+            for b in &values {
+                let ordering = Uint::ct_cmp(a, b);
+                assert_eq!(Ordering::from(ordering), a.cmp(b));
+                assert_eq!(bool::from(ordering.is_lt()), a < b);
+                assert_eq!(bool::from(ordering.is_eq()), a == b);
+                assert_eq!(bool::from(ordering.is_gt()), a > b);
+            }
+        }
+    }
+
+    #[test]
+    fn cmp_be_bytes() {
+        let values = [
+            U128::ZERO,
+            U128::ONE,
+            U128::from_u64(0x100),
+            U128::ONE << 64,
+            U128::MAX,
+        ];
+
+        for a in &values {
+            for b in &values {
+                let (a_bytes, b_bytes) = (a.to_be_bytes(), b.to_be_bytes());
+                assert_eq!(a.cmp_be_bytes(b), a_bytes.cmp(&b_bytes));
+                assert_eq!(a.cmp_be_bytes(b), a.cmp(b));
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn cmp_be_bytes_random() {
+        use crate::Random;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        for _ in 0..1000 {
+            let a = U256::random(&mut rng);
+            // Also exercise values sharing their most significant limbs
+            let b = Uint::conditional_select(
+                &U256::random(&mut rng),
+                &a.wrapping_add(&U256::from_u8(1)),
+                (rng.next_u32() as u8 & 1).into(),
+            );
+            let (a_bytes, b_bytes) = (a.to_be_bytes(), b.to_be_bytes());
+            assert_eq!(a.cmp_be_bytes(&b), a_bytes.cmp(&b_bytes));
+            assert_eq!(b.cmp_be_bytes(&a), b_bytes.cmp(&a_bytes));
+        }
+
+        let mut values = [(); 32].map(|_| U256::random(&mut rng));
+        let mut encodings = values.map(|x| x.to_be_bytes());
+        values.sort_by(Uint::cmp_be_bytes);
+        encodings.sort();
+        assert_eq!(values.map(|x| x.to_be_bytes()), encodings);
+    }
+
+    #[test]
+    fn ct_cmp_sorting_network() {
+        let mut values = [U128::from_u64(42), U128::MAX, U128::ZERO, U128::from_u64(7)];
+
+        // Optimal sorting network for 4 inputs
+        for (i, j) in [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)] {
+            let (lo, hi) = values.split_at_mut(j);
+            let swap = Uint::ct_cmp(&lo[i], &hi[0]).is_gt();
+            U128::conditional_swap(&mut lo[i], &mut hi[0], swap);
+        }
+
+        assert_eq!(
+            values,
+            [U128::ZERO, U128::from_u64(7), U128::from_u64(42), U128::MAX]
+        );
+    }
+
     #[test]
     fn ct_eq() {
         let a = U128::ZERO;
@@ -197,4 +496,58 @@ mod tests {
         assert!(!bool::from(c.ct_lt(&a)));
         assert!(!bool::from(c.ct_lt(&b)));
     }
+
+    #[test]
+    fn cmp_u64() {
+        let a = U256::from(1000u64);
+
+        assert!(a == 1000u64);
+        assert!(a != 999u64);
+        assert!(a < 1001u64);
+        assert!(a > 999u64);
+        assert!(a <= 1000u64);
+        assert!(U256::ZERO == 0u64);
+        assert!(U256::MAX > u64::MAX);
+        assert!(U64::MAX == u64::MAX);
+    }
+
+    #[test]
+    fn cmp_u128() {
+        let a = U256::from(u128::MAX);
+
+        assert!(a == u128::MAX);
+        assert!(a > u128::MAX - 1);
+        assert!(a.wrapping_add(&U256::ONE) > u128::MAX);
+        assert!(U128::MAX == u128::MAX);
+        assert!(U128::ONE < u128::MAX);
+
+        // `rhs` wider than `self`
+        assert!(U64::MAX < u128::MAX);
+        assert!(U64::MAX == u128::from(u64::MAX));
+        assert!(U64::MAX < u128::from(u64::MAX) + 1);
+    }
+
+    #[test]
+    fn ct_min_nonzero() {
+        let values = [
+            U128::ZERO,
+            U128::from(17u8),
+            U128::MAX,
+            U128::ZERO,
+            U128::from(5u8),
+            U128::from(9u8),
+        ];
+        assert_eq!(U128::ct_min_nonzero(&values).unwrap(), U128::from(5u8));
+        assert_eq!(
+            U128::ct_min_nonzero(&values[..3]).unwrap(),
+            U128::from(17u8)
+        );
+        assert_eq!(U128::ct_min_nonzero(&[U128::MAX]).unwrap(), U128::MAX);
+    }
+
+    #[test]
+    fn ct_min_nonzero_all_zero() {
+        assert!(bool::from(U128::ct_min_nonzero(&[U128::ZERO; 4]).is_none()));
+        assert!(bool::from(U128::ct_min_nonzero(&[]).is_none()));
+    }
 }
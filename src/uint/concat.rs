@@ -1,3 +1,6 @@
+use super::Uint;
+use crate::Limb;
+
 // TODO(tarcieri): use `const_evaluatable_checked` when stable to make generic around bits.
 macro_rules! impl_concat {
     ($(($name:ident, $bits:expr)),+) => {
@@ -44,9 +47,43 @@ macro_rules! impl_concat {
      };
 }
 
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Concatenate two values of possibly different sizes, with `hi` as most significant
+    /// and `lo` as the least significant.
+    ///
+    /// The number of limbs of the result is usually inferred, e.g. a [`U320`][crate::U320]
+    /// can be built from a [`U256`][crate::U256] and a [`U64`][crate::U64].
+    ///
+    /// Panics if `OUTPUT_LIMBS != LIMBS + HI_LIMBS`.
+    pub const fn concat_mixed<const HI_LIMBS: usize, const OUTPUT_LIMBS: usize>(
+        lo: &Self,
+        hi: &Uint<HI_LIMBS>,
+    ) -> Uint<OUTPUT_LIMBS> {
+        assert!(
+            OUTPUT_LIMBS == LIMBS + HI_LIMBS,
+            "output size must be the sum of the input sizes"
+        );
+
+        let mut limbs = [Limb::ZERO; OUTPUT_LIMBS];
+        let mut i = 0;
+
+        while i < LIMBS {
+            limbs[i] = lo.limbs[i];
+            i += 1;
+        }
+
+        while i < OUTPUT_LIMBS {
+            limbs[i] = hi.limbs[i - LIMBS];
+            i += 1;
+        }
+
+        Uint { limbs }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{U128, U64};
+    use crate::{Uint, U128, U192, U256, U320, U64};
 
     #[test]
     fn concat() {
@@ -66,4 +103,35 @@ mod tests {
         let res: U128 = U64::ONE.square_wide().into();
         assert_eq!(res, U128::ONE);
     }
+
+    #[test]
+    fn concat_mixed() {
+        let lo =
+            U256::from_be_hex("00112233445566778899aabbccddeeff0123456789abcdeffedcba9876543210");
+        let hi = U64::from_u64(0xdead_beef_0000_0001);
+        let res: U320 = Uint::concat_mixed(&lo, &hi);
+        assert_eq!(
+            res,
+            U320::from_be_hex(
+                "deadbeef0000000100112233445566778899aabbccddeeff0123456789abcdeffedcba9876543210"
+            )
+        );
+
+        let res: U192 = Uint::concat_mixed(&U64::MAX, &U128::ONE);
+        assert_eq!(res, U192::MAX.shr_vartime(127));
+    }
+
+    #[test]
+    fn concat_mixed_matches_concat() {
+        let hi = U64::from_u64(0x0011223344556677);
+        let lo = U64::from_u64(0x8899aabbccddeeff);
+        let res: U128 = Uint::concat_mixed(&lo, &hi);
+        assert_eq!(res, hi.concat(&lo));
+    }
+
+    #[test]
+    #[should_panic(expected = "output size must be the sum of the input sizes")]
+    fn concat_mixed_wrong_size() {
+        let _: U256 = Uint::concat_mixed(&U64::ONE, &U64::ONE);
+    }
 }
@@ -1,7 +1,9 @@
 //! [`Uint`] division operations.
 
-use super::div_limb::{div_rem_limb_with_reciprocal, Reciprocal};
-use crate::{CtChoice, Limb, NonZero, Uint, Word, Wrapping};
+use super::div_limb::{
+    div_rem_limb_wider_with_reciprocal, div_rem_limb_with_reciprocal, Reciprocal,
+};
+use crate::{CtChoice, Limb, NonZero, Uint, Word, Wrapping, U64};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use subtle::CtOption;
 
@@ -172,6 +174,52 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (q, r)
     }
 
+    /// Computes `self / divisor` for a divisor with `D <= LIMBS` limbs,
+    /// returns the quotient and the remainder.
+    ///
+    /// The remainder is always smaller than `divisor`, so it is returned with `D` limbs.
+    /// The division goes limb by limb (Algorithm D in Knuth's TAOCP vol. 2, 4.3.1): each
+    /// of the `LIMBS` steps divides a `D + 1` limb value by `divisor`, using a reciprocal
+    /// of its top limb computed once, instead of the bit by bit steps of [`Uint::div_rem`].
+    ///
+    /// NOTE: this operation is variable time with respect to the bit length of `divisor`
+    /// *ONLY*.
+    ///
+    /// Panics if `D > LIMBS`.
+    pub fn div_rem_wide<const D: usize>(&self, divisor: &NonZero<Uint<D>>) -> (Self, Uint<D>) {
+        assert!(D <= LIMBS, "divisor must not be wider than the dividend");
+        let divisor = divisor.as_ref();
+
+        // Normalize the divisor so that its top bit is set
+        let shift = divisor.leading_zeros();
+        let m = divisor.shl_vartime(shift);
+        let (reciprocal, _) = Reciprocal::ct_new(m.limbs[D - 1]);
+
+        let mut quo = Self::ZERO;
+        let mut rem = Uint::<D>::ZERO;
+
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+
+            // `rem * 2^Limb::BITS + self.limbs[i]`, which is less than `divisor * 2^Limb::BITS`
+            let mut lo = Uint::<D>::ZERO;
+            lo.limbs[0] = self.limbs[i];
+            let mut j = 1;
+            while j < D {
+                lo.limbs[j] = rem.limbs[j - 1];
+                j += 1;
+            }
+            let hi = rem.limbs[D - 1];
+
+            let (q, r) = div_rem_limb_wider_with_reciprocal(&lo, hi, &m, shift, &reciprocal);
+            quo.limbs[i] = q;
+            rem = r;
+        }
+
+        (quo, rem)
+    }
+
     /// Computes self % rhs, returns the remainder.
     pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
         // Since `rhs` is nonzero, this should always hold.
@@ -179,6 +227,38 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         r
     }
 
+    /// Computes `self % modulus`, returning the remainder as a [`NonZero`],
+    /// or none if `self` is a multiple of `modulus`.
+    ///
+    /// This is variable only with respect to `modulus`.
+    pub fn reduce_nonzero(&self, modulus: &NonZero<Self>) -> CtOption<NonZero<Self>> {
+        NonZero::new(self.rem(modulus))
+    }
+
+    /// Computes the largest `x < bound` such that `x ≡ self (mod modulus)`,
+    /// or none if there is no such value, i.e. if `self % modulus >= bound`.
+    ///
+    /// This is variable only with respect to `modulus`.
+    pub fn largest_congruent_below(&self, modulus: &NonZero<Self>, bound: &Self) -> CtOption<Self> {
+        let r = self.rem(modulus);
+        let exists = Self::ct_lt(&r, bound);
+
+        // `bound - 1 - ((bound - 1 - r) % modulus)`, which is meaningless if `bound <= r`
+        let max = bound.wrapping_sub(&Self::ONE);
+        let x = max.wrapping_sub(&max.wrapping_sub(&r).rem(modulus));
+        CtOption::new(x, exists.into())
+    }
+
+    /// Computes `(hi * 2^BITS + lo) % modulus`, returns the remainder.
+    ///
+    /// This reduces the concatenation of `hi` and `lo` without requiring a
+    /// double-width [`Uint`] type with a [`Concat`][`crate::Concat`] impl.
+    pub fn rem_concat(hi: &Self, lo: &Self, modulus: &NonZero<Self>) -> Self {
+        // Since `modulus` is nonzero, this should always hold.
+        let (r, _c) = Self::const_rem_wide((*lo, *hi), modulus);
+        r
+    }
+
     /// Wrapped division is just normal division i.e. `self` / `rhs`
     /// There’s no way wrapping could ever happen.
     /// This function exists, so that all operations are accounted for in the wrapping operations.
@@ -191,12 +271,13 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Perform checked division, returning a [`CtOption`] which `is_some`
-    /// only if the rhs != 0
+    /// only if the rhs != 0.
+    ///
+    /// Unlike [`Uint::wrapping_div`], this never panics: division by zero
+    /// results in `none()`.
     pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
-        NonZero::new(*rhs).map(|rhs| {
-            let (q, _r) = self.div_rem(&rhs);
-            q
-        })
+        let (q, _r, is_some) = self.ct_div_rem(rhs);
+        CtOption::new(q, is_some.into())
     }
 
     /// Wrapped (modular) remainder calculation is just `self` % `rhs`.
@@ -211,9 +292,126 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Perform checked reduction, returning a [`CtOption`] which `is_some`
-    /// only if the rhs != 0
+    /// only if the rhs != 0.
+    ///
+    /// Unlike [`Uint::wrapping_rem`], this never panics: reduction modulo zero
+    /// results in `none()`.
     pub fn checked_rem(&self, rhs: &Self) -> CtOption<Self> {
-        NonZero::new(*rhs).map(|rhs| self.rem(&rhs))
+        let (r, is_some) = self.const_rem(rhs);
+        CtOption::new(r, is_some.into())
+    }
+
+    /// Computes the quotient of the Euclidean division of `self` by `rhs`.
+    ///
+    /// For unsigned integers this is the same as truncating division, i.e. [`Uint::div_rem`];
+    /// it is provided under the same name as [`u64::div_euclid`] for generic code.
+    pub fn div_euclid(&self, rhs: &NonZero<Self>) -> Self {
+        let (q, _r) = self.div_rem(rhs);
+        q
+    }
+
+    /// Computes the least nonnegative remainder of `self` modulo `rhs`.
+    ///
+    /// For unsigned integers this is the same as [`Uint::rem`]; it is provided under
+    /// the same name as [`u64::rem_euclid`] for generic code.
+    pub fn rem_euclid(&self, rhs: &NonZero<Self>) -> Self {
+        self.rem(rhs)
+    }
+
+    /// Computes `self % modulus` for a modulus which fits in a `u64`, using Horner's method
+    /// over the limbs of `self` instead of a full-width division.
+    ///
+    /// Each step reduces a value one limb wider than the modulus, using a single reciprocal
+    /// of the normalized modulus computed upfront.
+    ///
+    /// NOTE: the bit length of `modulus` is leaked in the time pattern.
+    ///
+    /// Panics if `modulus` is zero.
+    pub const fn rem_u64(&self, modulus: u64) -> u64 {
+        assert!(modulus != 0, "modulo zero");
+
+        // Normalize the modulus so that its top bit is set
+        let m = U64::from_u64(modulus);
+        let shift = m.leading_zeros();
+        let m = m.shl_vartime(shift);
+        let (reciprocal, _) = Reciprocal::ct_new(m.limbs[U64::LIMBS - 1]);
+
+        let mut rem = U64::ZERO;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+
+            // `rem * 2^Limb::BITS + self.limbs[i]`, which is less than `modulus * 2^Limb::BITS`
+            let mut lo = U64::ZERO;
+            lo.limbs[0] = self.limbs[i];
+            let mut j = 1;
+            while j < U64::LIMBS {
+                lo.limbs[j] = rem.limbs[j - 1];
+                j += 1;
+            }
+            let hi = rem.limbs[U64::LIMBS - 1];
+
+            rem = div_rem_limb_wider_with_reciprocal(&lo, hi, &m, shift, &reciprocal).1;
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        let result = (rem.limbs[0].0 as u64) | ((rem.limbs[1].0 as u64) << 32);
+        #[cfg(target_pointer_width = "64")]
+        let result = rem.limbs[0].0;
+        result
+    }
+
+    /// Returns the logarithm of `self` with respect to `base`, rounded down,
+    /// computed by repeated division.
+    ///
+    /// For a base of 2, [`Uint::ilog2`] is much faster.
+    ///
+    /// NOTE: this operation is variable time.
+    ///
+    /// Panics if `self` is zero or if `base` is less than 2, like the `ilog` methods
+    /// of the primitive integer types.
+    pub const fn ilog(&self, base: &Self) -> u32 {
+        assert!(
+            self.ct_is_nonzero().is_true_vartime(),
+            "argument of integer logarithm must be positive"
+        );
+        assert!(
+            Self::ct_gt(base, &Self::ONE).is_true_vartime(),
+            "base of integer logarithm must be at least 2"
+        );
+
+        let mut n = *self;
+        let mut log = 0;
+        while !Self::ct_lt(&n, base).is_true_vartime() {
+            n = n.wrapping_div(base);
+            log += 1;
+        }
+        log
+    }
+
+    /// Returns the number of digits needed to write `self` in the given `radix`,
+    /// which is `1` for zero, without formatting it.
+    ///
+    /// Power-of-two radixes are computed from [`Uint::bits_vartime`], others with
+    /// [`Uint::ilog`].
+    ///
+    /// NOTE: this operation is variable time.
+    ///
+    /// Panics if `radix` is less than 2.
+    pub const fn num_digits(&self, radix: u32) -> usize {
+        assert!(radix >= 2, "radix must be at least 2");
+
+        let bits = self.bits_vartime();
+        if bits == 0 {
+            return 1;
+        }
+
+        if radix.is_power_of_two() {
+            let digit_bits = radix.trailing_zeros() as usize;
+            (bits + digit_bits - 1) / digit_bits
+        } else {
+            self.ilog(&Self::from_u32(radix)) as usize + 1
+        }
     }
 }
 
@@ -583,7 +781,7 @@ impl<const LIMBS: usize> RemAssign<&NonZero<Uint<LIMBS>>> for Wrapping<Uint<LIMB
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{limb::HI_BIT, Limb, U256};
+    use crate::{limb::HI_BIT, Limb, U128, U256, U512, U64};
 
     #[cfg(feature = "rand")]
     use {
@@ -593,6 +791,119 @@ mod tests {
         rand_core::SeedableRng,
     };
 
+    #[test]
+    fn rem_u64() {
+        let x =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        for m in [
+            1u64,
+            2,
+            3,
+            10,
+            1000,
+            0xffff_ffff,
+            0x1_0000_0001,
+            u64::MAX - 58,
+            u64::MAX,
+        ] {
+            let expected = x.rem(&NonZero::new(U256::from_u64(m)).unwrap());
+            assert_eq!(U256::from_u64(x.rem_u64(m)), expected);
+        }
+
+        assert_eq!(U256::ZERO.rem_u64(7), 0);
+        assert_eq!(U256::MAX.rem_u64(u64::MAX), 0);
+        assert_eq!(U64::from_u64(12345).rem_u64(100), 45);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rem_u64_random() {
+        let mut rng = ChaChaRng::from_seed([7u8; 32]);
+        for _ in 0..100 {
+            let x = U256::random(&mut rng);
+            let m = rng.next_u64() | 1;
+            let expected = x.rem(&NonZero::new(U256::from_u64(m)).unwrap());
+            assert_eq!(U256::from_u64(x.rem_u64(m)), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulo zero")]
+    fn rem_u64_zero() {
+        let _ = U256::ONE.rem_u64(0);
+    }
+
+    #[test]
+    fn ilog() {
+        let ten = U256::from_u8(10);
+        assert_eq!(U256::ONE.ilog(&ten), 0);
+        assert_eq!(U256::from_u8(9).ilog(&ten), 0);
+        assert_eq!(U256::from_u8(10).ilog(&ten), 1);
+        assert_eq!(U256::from_u64(999_999).ilog(&ten), 5);
+        assert_eq!(U256::from_u64(1_000_000).ilog(&ten), 6);
+        assert_eq!(U256::MAX.ilog(&ten), 77u32);
+
+        assert_eq!(U256::MAX.ilog(&U256::from_u8(2)), U256::MAX.ilog2());
+        assert_eq!(U256::MAX.ilog(&U256::MAX), 1);
+        assert_eq!(U256::from_u8(7).ilog(&U256::MAX), 0);
+
+        for (x, log) in [
+            (1, 0),
+            (2, 0),
+            (3, 1),
+            (80, 3),
+            (81, 4),
+            (12345, 8),
+            (u64::MAX, 40),
+        ] {
+            assert_eq!(U64::from_u64(x).ilog(&U64::from_u8(3)), log);
+        }
+    }
+
+    #[test]
+    fn num_digits() {
+        for radix in [2, 3, 8, 10, 16, 36] {
+            assert_eq!(U256::ZERO.num_digits(radix), 1);
+            assert_eq!(U256::ONE.num_digits(radix), 1);
+        }
+
+        assert_eq!(U256::from_u8(9).num_digits(10), 1);
+        assert_eq!(U256::from_u8(10).num_digits(10), 2);
+        assert_eq!(U256::from_u64(999_999).num_digits(10), 6);
+        assert_eq!(U256::from_u64(1_000_000).num_digits(10), 7);
+        assert_eq!(U256::MAX.num_digits(10), 78);
+
+        assert_eq!(U256::from_u8(0xf).num_digits(16), 1);
+        assert_eq!(U256::from_u8(0x10).num_digits(16), 2);
+        assert_eq!(U256::MAX.num_digits(16), 64);
+        assert_eq!(U256::MAX.num_digits(2), 256);
+        assert_eq!(U256::MAX.num_digits(8), 86);
+        assert_eq!(U256::from_u8(8).num_digits(8), 2);
+        assert_eq!(U256::from_u8(7).num_digits(8), 1);
+
+        assert_eq!(U64::from_u64(35).num_digits(36), 1);
+        assert_eq!(U64::from_u64(36).num_digits(36), 2);
+        assert_eq!(U64::MAX.num_digits(3), 41);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be at least 2")]
+    fn num_digits_radix_one() {
+        let _ = U256::ONE.num_digits(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "argument of integer logarithm must be positive")]
+    fn ilog_zero() {
+        let _ = U256::ZERO.ilog(&U256::from_u8(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn ilog_base_one() {
+        let _ = U256::from_u8(10).ilog(&U256::ONE);
+    }
+
     #[test]
     fn div_word() {
         for (n, d, e, ee) in &[
@@ -661,6 +972,40 @@ mod tests {
         assert_eq!(r, U256::ZERO);
     }
 
+    #[test]
+    fn checked_div() {
+        let x = U256::from(100u8);
+        assert_eq!(x.checked_div(&U256::from(7u8)).unwrap(), U256::from(14u8));
+        assert_eq!(U256::MAX.checked_div(&U256::MAX).unwrap(), U256::ONE);
+        assert!(bool::from(x.checked_div(&U256::ZERO).is_none()));
+        assert!(bool::from(U256::ZERO.checked_div(&U256::ZERO).is_none()));
+    }
+
+    #[test]
+    fn checked_rem() {
+        let x = U256::from(100u8);
+        assert_eq!(x.checked_rem(&U256::from(7u8)).unwrap(), U256::from(2u8));
+        assert_eq!(U256::MAX.checked_rem(&U256::MAX).unwrap(), U256::ZERO);
+        assert!(bool::from(x.checked_rem(&U256::ZERO).is_none()));
+        assert!(bool::from(U256::ZERO.checked_rem(&U256::ZERO).is_none()));
+    }
+
+    #[test]
+    fn euclid_matches_std() {
+        for (a, b) in [
+            (0u64, 1u64),
+            (100, 7),
+            (7, 100),
+            (u64::MAX, 3),
+            (u64::MAX, u64::MAX),
+        ] {
+            let x = U64::from_u64(a);
+            let y = NonZero::new(U64::from_u64(b)).unwrap();
+            assert_eq!(x.div_euclid(&y), U64::from_u64(a.div_euclid(b)));
+            assert_eq!(x.rem_euclid(&y), U64::from_u64(a.rem_euclid(b)));
+        }
+    }
+
     #[test]
     fn reduce_one() {
         let (r, is_some) = U256::from(10u8).const_rem(&U256::ONE);
@@ -702,6 +1047,40 @@ mod tests {
         assert_eq!(r, U256::from(3u8));
     }
 
+    #[test]
+    fn rem_concat() {
+        let hi =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let lo =
+            U256::from_be_hex("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251");
+        let modulus =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+        let actual = U256::rem_concat(&hi, &lo, &NonZero::new(modulus).unwrap());
+        let expected = hi
+            .concat(&lo)
+            .rem(&NonZero::new(U256::ZERO.concat(&modulus)).unwrap());
+        let (expected_hi, expected_lo) = expected.split();
+
+        assert_eq!(expected_hi, U256::ZERO);
+        assert_eq!(actual, expected_lo);
+    }
+
+    #[test]
+    fn rem_concat_small_modulus() {
+        let modulus = NonZero::new(U256::from(7u8)).unwrap();
+
+        // 2^256 = 2 mod 7
+        assert_eq!(
+            U256::rem_concat(&U256::ONE, &U256::ZERO, &modulus),
+            U256::from(2u8)
+        );
+        assert_eq!(
+            U256::rem_concat(&U256::ZERO, &U256::from(10u8), &modulus),
+            U256::from(3u8)
+        );
+    }
+
     #[test]
     fn reduce_max() {
         let mut a = U256::ZERO;
@@ -731,6 +1110,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn rem_trait() {
         let a = U256::from(10u64);
         let b = NonZero::new(U256::from(3u64)).unwrap();
@@ -741,4 +1121,118 @@ mod tests {
         assert_eq!(&a % b, c);
         assert_eq!(&a % &b, c);
     }
+
+    #[test]
+    fn reduce_nonzero() {
+        let modulus = NonZero::new(U256::from(7u8)).unwrap();
+
+        let r = U256::from(30u8).reduce_nonzero(&modulus);
+        assert_eq!(r.map(|r| *r).unwrap(), U256::from(2u8));
+        let r = U256::MAX.reduce_nonzero(&modulus);
+        assert_eq!(r.map(|r| *r).unwrap(), U256::MAX.rem(&modulus));
+        let r = U256::from(6u8).reduce_nonzero(&modulus);
+        assert_eq!(r.map(|r| *r).unwrap(), U256::from(6u8));
+    }
+
+    #[test]
+    fn reduce_nonzero_to_zero() {
+        let modulus = NonZero::new(U256::from(7u8)).unwrap();
+
+        assert!(bool::from(U256::ZERO.reduce_nonzero(&modulus).is_none()));
+        assert!(bool::from(
+            U256::from(7u8).reduce_nonzero(&modulus).is_none()
+        ));
+        assert!(bool::from(
+            U256::from(49u8).reduce_nonzero(&modulus).is_none()
+        ));
+        assert!(bool::from(
+            U256::ONE
+                .reduce_nonzero(&NonZero::new(U256::ONE).unwrap())
+                .is_none()
+        ));
+    }
+
+    #[test]
+    fn largest_congruent_below() {
+        let modulus = NonZero::new(U256::from(7u8)).unwrap();
+        let check = |x: u8, bound: u8| {
+            U256::from(x)
+                .largest_congruent_below(&modulus, &U256::from(bound))
+                .map(|x| x.as_words()[0] as u8)
+        };
+
+        assert_eq!(check(3, 100).unwrap(), 94);
+        assert_eq!(check(3, 95).unwrap(), 94);
+        assert_eq!(check(3, 94).unwrap(), 87);
+        assert_eq!(check(100, 50).unwrap(), 44);
+        assert_eq!(check(3, 4).unwrap(), 3);
+        assert_eq!(check(0, 1).unwrap(), 0);
+
+        let x = U256::MAX.largest_congruent_below(&modulus, &U256::MAX);
+        assert_eq!(x.unwrap(), U256::MAX.wrapping_sub(&modulus));
+    }
+
+    #[test]
+    fn largest_congruent_below_none() {
+        let modulus = NonZero::new(U256::from(7u8)).unwrap();
+
+        // All values congruent to 3 are at least 3
+        let x = U256::from(10u8).largest_congruent_below(&modulus, &U256::from(3u8));
+        assert!(bool::from(x.is_none()));
+        let x = U256::from(3u8).largest_congruent_below(&modulus, &U256::from(2u8));
+        assert!(bool::from(x.is_none()));
+        let x = U256::ZERO.largest_congruent_below(&modulus, &U256::ZERO);
+        assert!(bool::from(x.is_none()));
+    }
+
+    #[test]
+    fn div_rem_wide() {
+        let x = U512::from_be_hex(concat![
+            "8c27b8e8c1c5c2ba4b1bfb6a4aa5f92f7fb7bdf7cca5c5fd8c7a3c6cf7bb9a27",
+            "e4e075f44806aa98fba4cd3b264774e1a636beb2f4bd2eeab0c923775e24d383"
+        ]);
+
+        let divisors = [
+            U256::ONE,
+            U256::from(7u8),
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            U256::MAX,
+        ];
+        for divisor in divisors {
+            let (q, r) = x.div_rem_wide(&NonZero::new(divisor).unwrap());
+            let (expected_q, expected_r) = x.div_rem(&NonZero::new(divisor.resize()).unwrap());
+            assert_eq!(q, expected_q);
+            assert_eq!(r.resize::<{ U512::LIMBS }>(), expected_r);
+        }
+
+        // Same-size and single limb divisors
+        let (q, r) = x.div_rem_wide(&NonZero::new(x).unwrap());
+        assert_eq!((q, r), (U512::ONE, U512::ZERO));
+        let (q, r) = U256::MAX.div_rem_wide(&NonZero::new(Uint::<1>::from_u8(10)).unwrap());
+        assert_eq!(q, U256::MAX.wrapping_div(&U256::from(10u8)));
+        assert_eq!(r, Uint::<1>::from_u8(5));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn div_rem_wide_random() {
+        let mut rng = ChaChaRng::from_seed([7u8; 32]);
+        for _ in 0..100 {
+            let x = U512::random(&mut rng);
+
+            // Divisors of every bit length, so that the quotient estimates need corrections
+            let shift = (rng.next_u32() % 256) as usize;
+            let divisor = U256::random(&mut rng).shr_vartime(shift) | U256::ONE;
+            let (q, r) = x.div_rem_wide(&NonZero::new(divisor).unwrap());
+            let (expected_q, expected_r) = x.div_rem(&NonZero::new(divisor.resize()).unwrap());
+            assert_eq!(q, expected_q);
+            assert_eq!(r.resize::<{ U512::LIMBS }>(), expected_r);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor must not be wider than the dividend")]
+    fn div_rem_wide_too_wide() {
+        let _ = U64::MAX.div_rem_wide(&NonZero::new(U128::ONE).unwrap());
+    }
 }
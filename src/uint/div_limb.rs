@@ -131,7 +131,7 @@ const fn addhilo(x_hi: Word, x_lo: Word, y_hi: Word, y_lo: Word) -> (Word, Word)
 /// Calculate the quotient and the remainder of the division of a wide word
 /// (supplied as high and low words) by `d`, with a precalculated reciprocal `v`.
 #[inline(always)]
-const fn div2by1(u1: Word, u0: Word, reciprocal: &Reciprocal) -> (Word, Word) {
+pub(crate) const fn div2by1(u1: Word, u0: Word, reciprocal: &Reciprocal) -> (Word, Word) {
     let d = reciprocal.divisor_normalized;
 
     debug_assert!(d >= (1 << (Word::BITS - 1)));
@@ -269,6 +269,66 @@ pub(crate) const fn div_rem_limb_with_reciprocal<const L: usize>(
     (Uint::<L>::new(q), Limb(r >> reciprocal.shift))
 }
 
+/// Divides `(lo, hi)` by `m`, for a value `(lo, hi) < m * 2^Limb::BITS`, i.e. at most one limb
+/// wider than the divisor, given `normalized_modulus = m << shift` with its top bit set
+/// and the reciprocal of its top limb. Returns the single limb quotient and the remainder.
+///
+/// A single quotient limb estimate (Algorithm D in Knuth's TAOCP vol. 2, 4.3.1) is enough for
+/// the division.
+///
+/// NOTE: the bit length of `m` is leaked in the time pattern.
+pub(crate) const fn div_rem_limb_wider_with_reciprocal<const L: usize>(
+    lo: &Uint<L>,
+    hi: Limb,
+    normalized_modulus: &Uint<L>,
+    shift: usize,
+    leading_reciprocal: &Reciprocal,
+) -> (Limb, Uint<L>) {
+    let m = normalized_modulus;
+    let d = m.limbs[L - 1];
+
+    // `(lo, hi) < m * 2^Limb::BITS`, so the shifted value still fits in `L + 1` limbs
+    let (lo, hi) = Uint::shl_vartime_wide((*lo, Uint::from_word(hi.0)), shift);
+    let hi = hi.limbs[0];
+
+    // Estimate the quotient from the top limbs; it is at most 2 greater than the true one
+    let overflow = Limb::ct_le(d, hi);
+    let (q, _) = div2by1(
+        Limb::ct_select(hi, Limb::ZERO, overflow).0,
+        lo.limbs[L - 1].0,
+        leading_reciprocal,
+    );
+    let mut q = Limb::ct_select(Limb(q), Limb::MAX, overflow);
+
+    let mut r_lo = lo;
+    let mut carry = Limb::ZERO;
+    let mut borrow = Limb::ZERO;
+    let mut i = 0;
+    while i < L {
+        let (qm, c) = Limb::ZERO.mac(q, m.limbs[i], carry);
+        carry = c;
+        let (n, b) = r_lo.limbs[i].sbb(qm, borrow);
+        r_lo.limbs[i] = n;
+        borrow = b;
+        i += 1;
+    }
+    let (mut r_hi, _) = hi.sbb(carry, borrow);
+
+    // The remainder is now in `[-2 * m, m)`, add `m` back at most twice
+    let mut i = 0;
+    while i < 2 {
+        let is_negative = CtChoice::from_lsb(r_hi.0 >> (Limb::BITS - 1));
+        let (sum_lo, c) = r_lo.adc(m, Limb::ZERO);
+        let (sum_hi, _) = r_hi.adc(Limb::ZERO, c);
+        r_lo = Uint::ct_select(&r_lo, &sum_lo, is_negative);
+        r_hi = Limb::ct_select(r_hi, sum_hi, is_negative);
+        q = Limb::ct_select(q, q.wrapping_sub(Limb::ONE), is_negative);
+        i += 1;
+    }
+
+    (q, r_lo.shr_vartime(shift))
+}
+
 #[cfg(test)]
 mod tests {
     use super::{div2by1, Reciprocal};
@@ -6,8 +6,15 @@ mod der;
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "std")]
+mod io;
+
 use super::Uint;
-use crate::{Encoding, Limb, Word};
+use crate::{Encoding, Error, Limb, Word};
+use subtle::{Choice, ConditionallySelectable};
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Create a new [`Uint`] from the provided big endian bytes.
@@ -35,6 +42,10 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Create a new [`Uint`] from the provided big endian hex string.
+    ///
+    /// The string must contain exactly `Self::BYTES * 2` hex digits, i.e. include leading
+    /// zeros. Panics otherwise, which makes this suitable for constants; use
+    /// [`Uint::try_from_be_hex`] for strings supplied at runtime.
     pub const fn from_be_hex(hex: &str) -> Self {
         let bytes = hex.as_bytes();
 
@@ -86,12 +97,20 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Create a new [`Uint`] from the provided little endian hex string.
+    ///
+    /// Each pair of hex digits is one byte, and the bytes are in little endian order:
+    /// the string is the hex encoding of [`Encoding::to_le_bytes`], as printed by some
+    /// reference implementations.
+    ///
+    /// The string must contain exactly `Self::BYTES * 2` hex digits. Panics otherwise,
+    /// which makes this suitable for constants; use [`Uint::try_from_le_hex`] for strings
+    /// supplied at runtime.
     pub const fn from_le_hex(hex: &str) -> Self {
         let bytes = hex.as_bytes();
 
         assert!(
             bytes.len() == Limb::BYTES * LIMBS * 2,
-            "bytes are not the expected size"
+            "hex string is not the expected size"
         );
 
         let mut res = [Limb::ZERO; LIMBS];
@@ -112,6 +131,277 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::new(res)
     }
 
+    /// Create a new [`Uint`] from a big endian hex string supplied at runtime.
+    ///
+    /// Unlike [`Uint::from_be_hex`], which is meant for constants, this returns
+    /// [`Error::InvalidLength`] if the string is not `Self::BYTES * 2` characters long
+    /// and [`Error::InvalidHexDigit`] if it contains a non-hex character, instead of panicking.
+    pub fn try_from_be_hex(hex: &str) -> Result<Self, Error> {
+        validate_hex::<LIMBS>(hex)?;
+        Ok(Self::from_be_hex(hex))
+    }
+
+    /// Create a new [`Uint`] from a little endian hex string supplied at runtime.
+    ///
+    /// Unlike [`Uint::from_le_hex`], which is meant for constants, this returns
+    /// [`Error::InvalidLength`] if the string is not `Self::BYTES * 2` characters long
+    /// and [`Error::InvalidHexDigit`] if it contains a non-hex character, instead of panicking.
+    pub fn try_from_le_hex(hex: &str) -> Result<Self, Error> {
+        validate_hex::<LIMBS>(hex)?;
+        Ok(Self::from_le_hex(hex))
+    }
+
+    /// Create a new [`Uint`] from the provided decimal digits, most significant first.
+    ///
+    /// Each element of `digits` must be a value in `0..=9` (not an ASCII character).
+    /// Panics if a digit is out of range or if the value does not fit in `Self`.
+    pub const fn from_decimal_digits(digits: &[u8]) -> Self {
+        let mut res = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+
+        while i < digits.len() {
+            assert!(digits[i] < 10, "invalid decimal digit");
+
+            // res = res * 10 + digit
+            let mut carry = Limb(digits[i] as Word);
+            let mut j = 0;
+            while j < LIMBS {
+                let (n, c) = Limb::ZERO.mac(res[j], Limb(10), carry);
+                res[j] = n;
+                carry = c;
+                j += 1;
+            }
+            assert!(carry.0 == 0, "decimal value is too large for the Uint");
+
+            i += 1;
+        }
+
+        Uint::new(res)
+    }
+
+    /// Decode a minimal big endian two's complement encoding, such as the contents
+    /// of an ASN.1 DER `INTEGER`.
+    ///
+    /// Returns the magnitude of the decoded value along with a [`Choice`] which is truthy
+    /// if the value is negative. Returns `None` if `bytes` is empty, is not minimally
+    /// encoded (i.e. has a redundant leading `0x00` or `0xff` byte), or if the magnitude
+    /// does not fit in a [`Uint`].
+    ///
+    /// NOTE: this operation is variable time with respect to `bytes`.
+    pub fn from_be_bytes_signed(bytes: &[u8]) -> Option<(Self, Choice)> {
+        let (&first, rest) = bytes.split_first()?;
+        let is_negative = first & 0x80 != 0;
+        let pad = if is_negative { 0xff } else { 0x00 };
+
+        // The leading byte is redundant if it only extends the sign of the next one
+        if let Some(&second) = rest.first() {
+            if first == pad && (second & 0x80 != 0) == is_negative {
+                return None;
+            }
+        }
+
+        // A minimal encoding of a value fitting in `Self` has at most one sign byte
+        let (prefix, value) = match bytes.len().checked_sub(Limb::BYTES * LIMBS) {
+            None | Some(0) => (&[][..], bytes),
+            Some(1) => bytes.split_at(1),
+            Some(_) => return None,
+        };
+        if prefix.iter().any(|&b| b != pad) {
+            return None;
+        }
+
+        // Load the sign-extended value limb by limb, starting from the least significant one
+        let mut x = Self::ZERO;
+        for (i, limb) in x.limbs.iter_mut().enumerate() {
+            let end = value.len().saturating_sub(i * Limb::BYTES);
+            let start = end.saturating_sub(Limb::BYTES);
+            let mut word = [pad; Limb::BYTES];
+            word[Limb::BYTES - (end - start)..].copy_from_slice(&value[start..end]);
+            *limb = Limb(Word::from_be_bytes(word));
+        }
+
+        if is_negative {
+            let magnitude = Self::ZERO.wrapping_sub(&x);
+            // The magnitude overflows if the value is `-2^BITS`
+            if magnitude.ct_is_nonzero().is_true_vartime() {
+                Some((magnitude, Choice::from(1)))
+            } else {
+                None
+            }
+        } else {
+            Some((x, Choice::from(0)))
+        }
+    }
+
+    /// Decode the provided big endian bytes and assign them to `self` if `choice` is truthy,
+    /// leaving `self` unchanged otherwise.
+    ///
+    /// The bytes are decoded limb by limb directly into `self`, in constant time
+    /// with respect to both `bytes` and `choice`.
+    ///
+    /// Panics if `bytes.len()` is not `Self::BYTES`.
+    pub fn conditional_overwrite_be(&mut self, bytes: &[u8], choice: Choice) {
+        assert!(
+            bytes.len() == Limb::BYTES * LIMBS,
+            "bytes are not the expected size"
+        );
+
+        for (limb, chunk) in self
+            .limbs
+            .iter_mut()
+            .zip(bytes.chunks_exact(Limb::BYTES).rev())
+        {
+            let mut buf = [0u8; Limb::BYTES];
+            buf.copy_from_slice(chunk);
+            limb.conditional_assign(&Limb(Word::from_be_bytes(buf)), choice);
+        }
+    }
+
+    /// Encode `self` as the magnitude of a value whose sign is given by `is_negative`,
+    /// using the minimal big endian two's complement encoding, such as the contents
+    /// of an ASN.1 DER `INTEGER`.
+    ///
+    /// Non-negative values with the most significant bit set get a leading `0x00` byte.
+    /// Zero is always encoded as a single `0x00` byte, regardless of `is_negative`.
+    ///
+    /// NOTE: the length of the output depends on the value being encoded.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_signed(&self, is_negative: Choice) -> Vec<u8> {
+        let is_negative = is_negative & Choice::from(self.ct_is_nonzero());
+        let value = Self::conditional_select(self, &Self::ZERO.wrapping_sub(self), is_negative);
+        let pad = 0u8.wrapping_sub(is_negative.unwrap_u8());
+
+        let mut bytes = vec![pad; Limb::BYTES * LIMBS + 1];
+        value.write_be_bytes(&mut bytes[1..]);
+
+        // Drop the leading bytes which only extend the sign of the next one
+        let redundant = bytes
+            .windows(2)
+            .take_while(|pair| pair[0] == pad && pair[1] & 0x80 == pad & 0x80)
+            .count();
+        bytes.split_off(redundant)
+    }
+
+    /// Get the length in bytes of the minimal big endian encoding of `self`,
+    /// i.e. without leading zero bytes, as used by RLP and DER.
+    ///
+    /// Zero has an encoded length of `0`.
+    ///
+    /// NOTE: this operation is variable time with respect to `self`.
+    pub const fn encoded_len(&self) -> usize {
+        (self.bits_vartime() + 7) / 8
+    }
+
+    /// Serialize this [`Uint`] as a big-endian byte array of length `N`, which must be
+    /// `Self::BYTES`.
+    ///
+    /// Unlike [`Encoding::to_be_bytes`], this is available for any number of limbs, not
+    /// just the ones with a type alias. When evaluated in a const context, a wrong `N` is a
+    /// compile-time error.
+    ///
+    /// Panics if `N` is not `Self::BYTES`.
+    pub const fn to_be_bytes_generic<const N: usize>(&self) -> [u8; N] {
+        assert!(N == Limb::BYTES * LIMBS, "bytes are not the expected size");
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+
+        while i < LIMBS {
+            let limb = self.limbs[LIMBS - i - 1].0.to_be_bytes();
+            let mut j = 0;
+            while j < Limb::BYTES {
+                bytes[i * Limb::BYTES + j] = limb[j];
+                j += 1;
+            }
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Serialize this [`Uint`] as a little-endian byte array of length `N`, which must be
+    /// `Self::BYTES`.
+    ///
+    /// Unlike [`Encoding::to_le_bytes`], this is available for any number of limbs, not
+    /// just the ones with a type alias. When evaluated in a const context, a wrong `N` is a
+    /// compile-time error.
+    ///
+    /// Panics if `N` is not `Self::BYTES`.
+    pub const fn to_le_bytes_generic<const N: usize>(&self) -> [u8; N] {
+        assert!(N == Limb::BYTES * LIMBS, "bytes are not the expected size");
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+
+        while i < LIMBS {
+            let limb = self.limbs[i].0.to_le_bytes();
+            let mut j = 0;
+            while j < Limb::BYTES {
+                bytes[i * Limb::BYTES + j] = limb[j];
+                j += 1;
+            }
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Serialize this [`Uint`] as big-endian into a newly allocated vector of
+    /// `Self::BYTES` bytes.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_vec(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Limb::BYTES * LIMBS];
+        self.write_be_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Serialize this [`Uint`] as little-endian into a newly allocated vector of
+    /// `Self::BYTES` bytes.
+    #[cfg(feature = "alloc")]
+    pub fn to_le_bytes_vec(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Limb::BYTES * LIMBS];
+        self.write_le_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Serialize this [`Uint`] as big-endian with all leading zero bytes removed,
+    /// producing [`Uint::encoded_len`] bytes.
+    ///
+    /// Zero is encoded as an empty byte vector.
+    ///
+    /// NOTE: the length of the output depends on the value being encoded.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        self.to_be_bytes_vec()
+            .split_off(Limb::BYTES * LIMBS - self.encoded_len())
+    }
+
+    /// Serialize this [`Uint`] as big-endian into `out`, which must be exactly
+    /// `Self::BYTES` long.
+    ///
+    /// Returns [`Error::InvalidLength`] if the length of `out` does not match.
+    pub fn encode_be(&self, out: &mut [u8]) -> Result<(), Error> {
+        if out.len() != Limb::BYTES * LIMBS {
+            return Err(Error::InvalidLength);
+        }
+
+        self.write_be_bytes(out);
+        Ok(())
+    }
+
+    /// Serialize this [`Uint`] as little-endian into `out`, which must be exactly
+    /// `Self::BYTES` long.
+    ///
+    /// Returns [`Error::InvalidLength`] if the length of `out` does not match.
+    pub fn encode_le(&self, out: &mut [u8]) -> Result<(), Error> {
+        if out.len() != Limb::BYTES * LIMBS {
+            return Err(Error::InvalidLength);
+        }
+
+        self.write_le_bytes(out);
+        Ok(())
+    }
+
     /// Serialize this [`Uint`] as big-endian, writing it into the provided
     /// byte slice.
     #[inline]
@@ -146,6 +436,19 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 }
 
+/// Check that `hex` can be decoded into a `Uint<LIMBS>` without panicking.
+fn validate_hex<const LIMBS: usize>(hex: &str) -> Result<(), Error> {
+    if hex.len() != Limb::BYTES * LIMBS * 2 {
+        return Err(Error::InvalidLength);
+    }
+
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidHexDigit);
+    }
+
+    Ok(())
+}
+
 /// Decode a single byte encoded as two hexadecimal characters.
 const fn decode_hex_byte(bytes: [u8; 2]) -> u8 {
     let mut i = 0;
@@ -158,10 +461,7 @@ const fn decode_hex_byte(bytes: [u8; 2]) -> u8 {
             b @ b'a'..=b'f' => 10 + b - b'a',
             b @ b'A'..=b'F' => 10 + b - b'A',
             b => {
-                assert!(
-                    matches!(b, b'0'..=b'9' | b'a' ..= b'f' | b'A'..=b'F'),
-                    "invalid hex byte"
-                );
+                assert!(b.is_ascii_hexdigit(), "invalid hex byte");
                 0
             }
         };
@@ -174,11 +474,12 @@ const fn decode_hex_byte(bytes: [u8; 2]) -> u8 {
 
 #[cfg(test)]
 mod tests {
-    use crate::Limb;
+    use crate::{Error, Limb, U128, U256, U64};
     use hex_literal::hex;
+    use subtle::Choice;
 
     #[cfg(feature = "alloc")]
-    use {crate::U128, alloc::format};
+    use alloc::format;
 
     #[cfg(target_pointer_width = "32")]
     use crate::U64 as UintEx;
@@ -258,6 +559,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_le_hex_const() {
+        const BE: U256 =
+            U256::from_be_hex("00112233445566778899aabbccddeeff0123456789abcdeffedcba9876543210");
+        const LE: U256 =
+            U256::from_le_hex("1032547698badcfeefcdab8967452301ffeeddccbbaa99887766554433221100");
+        assert_eq!(LE, BE);
+        assert_eq!(
+            crate::Encoding::to_le_bytes(&LE),
+            hex!("1032547698badcfeefcdab8967452301ffeeddccbbaa99887766554433221100")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "hex string is not the expected size")]
+    fn from_le_hex_wrong_size() {
+        let _ = U128::from_le_hex("00112233");
+    }
+
+    #[test]
+    fn try_from_hex() {
+        let hex = "00112233445566778899aabbccddeeff";
+        assert_eq!(U128::try_from_be_hex(hex), Ok(U128::from_be_hex(hex)));
+        assert_eq!(U128::try_from_le_hex(hex), Ok(U128::from_le_hex(hex)));
+        assert_eq!(
+            U128::try_from_be_hex("00112233445566778899AABBCCDDEEFF"),
+            Ok(U128::from_be_hex(hex))
+        );
+
+        assert_eq!(U128::try_from_be_hex(""), Err(Error::InvalidLength));
+        assert_eq!(U128::try_from_be_hex(&hex[1..]), Err(Error::InvalidLength));
+        assert_eq!(U64::try_from_le_hex(hex), Err(Error::InvalidLength));
+        assert_eq!(
+            U128::try_from_be_hex("00112233445566778899aabbccddeefg"),
+            Err(Error::InvalidHexDigit)
+        );
+        assert_eq!(
+            U128::try_from_le_hex("0x112233445566778899aabbccddeeff"),
+            Err(Error::InvalidHexDigit)
+        );
+        // Multi-byte characters must not be split
+        assert_eq!(
+            U64::try_from_be_hex("00112233445566\u{e9}"),
+            Err(Error::InvalidHexDigit)
+        );
+    }
+
+    #[test]
+    fn from_decimal_digits() {
+        const N: U256 = U256::from_decimal_digits(&[
+            1, 1, 5, 7, 9, 2, 0, 8, 9, 2, 1, 0, 3, 5, 6, 2, 4, 8, 7, 6, 2, 6, 9, 7, 4, 4, 6, 9, 4,
+            9, 4, 0, 7, 5, 7, 3, 5, 2, 9, 9, 9, 6, 9, 5, 5, 2, 2, 4, 1, 3, 5, 7, 6, 0, 3, 4, 2, 4,
+            2, 2, 2, 5, 9, 0, 6, 1, 0, 6, 8, 5, 1, 2, 0, 4, 4, 3, 6, 9,
+        ]);
+        assert_eq!(
+            N,
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551")
+        );
+
+        assert_eq!(U256::from_decimal_digits(&[]), U256::ZERO);
+        assert_eq!(U256::from_decimal_digits(&[0, 0, 4, 2]), U256::from(42u8));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid decimal digit")]
+    fn from_decimal_digits_invalid_digit() {
+        let _ = U256::from_decimal_digits(&[1, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal value is too large for the Uint")]
+    fn from_decimal_digits_overflow() {
+        // 2^128
+        let _ = U128::from_decimal_digits(&[
+            3, 4, 0, 2, 8, 2, 3, 6, 6, 9, 2, 0, 9, 3, 8, 4, 6, 3, 4, 6, 3, 3, 7, 4, 6, 0, 7, 4, 3,
+            1, 7, 6, 8, 2, 1, 1, 4, 5, 6,
+        ]);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn hex_upper() {
@@ -273,4 +653,211 @@ mod tests {
         let n = U128::from_be_hex(hex);
         assert_eq!(hex, format!("{:x}", n));
     }
+
+    #[test]
+    fn encode_be_le() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+
+        // Write into the middle of a larger frame
+        let mut frame = [0xaau8; 20];
+        assert_eq!(n.encode_be(&mut frame[2..18]), Ok(()));
+        assert_eq!(frame[2..18], hex!("00112233445566778899aabbccddeeff"));
+        assert_eq!(frame[..2], [0xaa; 2]);
+        assert_eq!(frame[18..], [0xaa; 2]);
+
+        assert_eq!(n.encode_le(&mut frame[4..]), Ok(()));
+        assert_eq!(frame[4..], hex!("ffeeddccbbaa99887766554433221100"));
+        assert_eq!(U128::from_le_slice(&frame[4..]), n);
+    }
+
+    #[test]
+    fn to_bytes_generic_without_alias() {
+        // 704-bit integers have no type alias, and hence no `Encoding` impl
+        type U704 = crate::Uint<{ 88 / Limb::BYTES }>;
+
+        let mut bytes = [0u8; 88];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        let n = U704::from_be_slice(&bytes);
+        assert_eq!(n.to_be_bytes_generic::<88>(), bytes);
+
+        bytes.reverse();
+        assert_eq!(n.to_le_bytes_generic::<88>(), bytes);
+        assert_eq!(U704::from_le_slice(&bytes), n);
+    }
+
+    #[test]
+    fn to_bytes_generic_const() {
+        const N: U128 = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        const BE: [u8; 16] = N.to_be_bytes_generic();
+        const LE: [u8; 16] = N.to_le_bytes_generic();
+        assert_eq!(BE, hex!("00112233445566778899aabbccddeeff"));
+        assert_eq!(LE, hex!("ffeeddccbbaa99887766554433221100"));
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes are not the expected size")]
+    fn to_bytes_generic_wrong_size() {
+        let _ = U128::ONE.to_be_bytes_generic::<15>();
+    }
+
+    #[test]
+    fn encode_invalid_length() {
+        let mut buf = [0u8; 17];
+        assert_eq!(U128::ONE.encode_be(&mut buf), Err(Error::InvalidLength));
+        assert_eq!(
+            U128::ONE.encode_le(&mut buf[..15]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(U128::ONE.encode_le(&mut []), Err(Error::InvalidLength));
+        assert_eq!(buf, [0u8; 17]);
+    }
+
+    #[test]
+    fn conditional_overwrite_be() {
+        let bytes = hex!("00112233445566778899aabbccddeeff");
+        let original = U128::from_be_hex("0123456789abcdef0123456789abcdef");
+
+        let mut n = original;
+        n.conditional_overwrite_be(&bytes, Choice::from(0));
+        assert_eq!(n, original);
+
+        n.conditional_overwrite_be(&bytes, Choice::from(1));
+        assert_eq!(n, U128::from_be_slice(&bytes));
+        assert_eq!(n, U128::from_be_hex("00112233445566778899aabbccddeeff"));
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes are not the expected size")]
+    fn conditional_overwrite_be_wrong_size() {
+        let mut n = U128::ZERO;
+        n.conditional_overwrite_be(&[0u8; 15], Choice::from(0));
+    }
+
+    #[test]
+    fn from_be_bytes_signed() {
+        let decode = |bytes: &[u8]| {
+            U64::from_be_bytes_signed(bytes).map(|(x, is_negative)| (x, bool::from(is_negative)))
+        };
+
+        assert_eq!(decode(&[0x00]), Some((U64::ZERO, false)));
+        assert_eq!(decode(&[0x7f]), Some((U64::from_u8(0x7f), false)));
+        assert_eq!(decode(&[0x00, 0x80]), Some((U64::from_u8(0x80), false)));
+        assert_eq!(decode(&[0xff]), Some((U64::ONE, true)));
+        assert_eq!(decode(&[0x80]), Some((U64::from_u8(0x80), true)));
+        assert_eq!(decode(&[0xff, 0x7f]), Some((U64::from_u8(0x81), true)));
+
+        // Full width values, with and without the sign byte
+        assert_eq!(decode(&hex!("00ffffffffffffffff")), Some((U64::MAX, false)));
+        assert_eq!(decode(&hex!("ff0000000000000001")), Some((U64::MAX, true)));
+        assert_eq!(
+            decode(&hex!("8000000000000000")),
+            Some((U64::ONE << 63, true))
+        );
+    }
+
+    #[test]
+    fn from_be_bytes_signed_invalid() {
+        // Empty
+        assert!(U64::from_be_bytes_signed(&[]).is_none());
+        // Non-minimal
+        assert!(U64::from_be_bytes_signed(&[0x00, 0x7f]).is_none());
+        assert!(U64::from_be_bytes_signed(&[0xff, 0x80]).is_none());
+        assert!(U64::from_be_bytes_signed(&[0x00, 0x00]).is_none());
+        // Too large
+        assert!(U64::from_be_bytes_signed(&hex!("010000000000000000")).is_none());
+        assert!(U64::from_be_bytes_signed(&hex!("ff0000000000000000")).is_none());
+        assert!(U64::from_be_bytes_signed(&hex!("000080000000000000000000")).is_none());
+    }
+
+    #[test]
+    fn from_be_bytes_signed_x509_serial() {
+        // Positive serial number whose top bit is set, so it carries a `0x00` pad byte
+        let serial = hex!("00ab0123456789abcdef0123456789abcd");
+        let decoded = U128::from_be_bytes_signed(&serial)
+            .map(|(x, is_negative)| (x, bool::from(is_negative)));
+        let expected = U128::from_be_hex("ab0123456789abcdef0123456789abcd");
+        assert_eq!(decoded, Some((expected, false)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_be_bytes_signed() {
+        let encode = |x: U64, is_negative: u8| x.to_be_bytes_signed(Choice::from(is_negative));
+
+        assert_eq!(encode(U64::ZERO, 0), [0x00]);
+        assert_eq!(encode(U64::ZERO, 1), [0x00]);
+        assert_eq!(encode(U64::from_u8(0x7f), 0), [0x7f]);
+        assert_eq!(encode(U64::from_u8(0x80), 0), [0x00, 0x80]);
+        assert_eq!(encode(U64::ONE, 1), [0xff]);
+        assert_eq!(encode(U64::from_u8(0x80), 1), [0x80]);
+        assert_eq!(encode(U64::from_u8(0x81), 1), [0xff, 0x7f]);
+        assert_eq!(encode(U64::MAX, 0), hex!("00ffffffffffffffff"));
+        assert_eq!(encode(U64::MAX, 1), hex!("ff0000000000000001"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn signed_roundtrip() {
+        let values = [
+            U128::ZERO,
+            U128::ONE,
+            U128::from_u8(0x80),
+            U128::from_u64(0xffff),
+            U128::ONE << 127,
+            U128::MAX,
+            U128::from_be_hex("00000000ab0123456789abcdef012345"),
+        ];
+
+        for x in values {
+            for is_negative in [0, 1] {
+                let encoded = x.to_be_bytes_signed(is_negative.into());
+                let decoded = U128::from_be_bytes_signed(&encoded)
+                    .map(|(decoded, is_negative)| (decoded, bool::from(is_negative)));
+                let expected_sign = is_negative == 1 && x != U128::ZERO;
+                assert_eq!(decoded, Some((x, expected_sign)));
+            }
+        }
+    }
+
+    #[test]
+    fn encoded_len() {
+        assert_eq!(U128::ZERO.encoded_len(), 0);
+        assert_eq!(U128::ONE.encoded_len(), 1);
+        assert_eq!(U128::from_u8(0xff).encoded_len(), 1);
+        assert_eq!(U128::from_u16(0x100).encoded_len(), 2);
+        assert_eq!(U128::from_u64(1 << 63).encoded_len(), 8);
+        assert_eq!(U128::from_u128(1 << 64).encoded_len(), 9);
+        assert_eq!(U128::MAX.encoded_len(), 16);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_bytes_vec() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        assert_eq!(
+            n.to_be_bytes_vec(),
+            hex!("00112233445566778899aabbccddeeff")
+        );
+        assert_eq!(
+            n.to_le_bytes_vec(),
+            hex!("ffeeddccbbaa99887766554433221100")
+        );
+        assert_eq!(U128::ZERO.to_be_bytes_vec(), [0u8; 16]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_be_bytes_trimmed() {
+        assert!(U128::ZERO.to_be_bytes_trimmed().is_empty());
+        assert_eq!(U128::ONE.to_be_bytes_trimmed(), [0x01]);
+        assert_eq!(U128::from_u16(0x100).to_be_bytes_trimmed(), [0x01, 0x00]);
+        assert_eq!(
+            U128::from_be_hex("000000000000ab0123456789abcdef01").to_be_bytes_trimmed(),
+            hex!("ab0123456789abcdef01")
+        );
+        assert_eq!(U128::MAX.to_be_bytes_trimmed(), [0xff; 16]);
+    }
 }
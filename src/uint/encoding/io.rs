@@ -0,0 +1,171 @@
+//! Support for encoding/decoding [`Uint`] to/from [`std::io`] streams.
+
+use crate::{Limb, Uint, Word};
+use std::io::{self, Read, Write};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Serialize this [`Uint`] as big-endian, writing it to the provided
+    /// [`Write`] implementation.
+    ///
+    /// Always writes exactly [`Uint::BYTES`] bytes.
+    pub fn write_be<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for limb in self.limbs.iter().rev() {
+            writer.write_all(&limb.0.to_be_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Serialize this [`Uint`] as little-endian, writing it to the provided
+    /// [`Write`] implementation.
+    ///
+    /// Always writes exactly [`Uint::BYTES`] bytes.
+    pub fn write_le<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for limb in self.limbs.iter() {
+            writer.write_all(&limb.0.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Serialize this [`Uint`] as big-endian with leading zero bytes stripped,
+    /// writing it to the provided [`Write`] implementation.
+    ///
+    /// Zero is encoded as an empty byte string. Returns the number of bytes
+    /// written, which is required to decode the value with
+    /// [`Uint::read_be_minimal`].
+    ///
+    /// NOTE: this operation is variable time with respect to the number of
+    /// leading zero bytes of `self`.
+    pub fn write_be_minimal<W: Write>(&self, writer: &mut W) -> io::Result<usize> {
+        let mut written = 0;
+
+        for limb in self.limbs.iter().rev() {
+            let bytes = limb.0.to_be_bytes();
+            let offset = if written == 0 {
+                (limb.0.leading_zeros() / 8) as usize
+            } else {
+                0
+            };
+
+            writer.write_all(&bytes[offset..])?;
+            written += Limb::BYTES - offset;
+        }
+
+        Ok(written)
+    }
+
+    /// Decode a [`Uint`] from exactly [`Uint::BYTES`] big-endian bytes read
+    /// from the provided [`Read`] implementation.
+    pub fn read_be<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut buf = [0u8; Limb::BYTES];
+
+        for limb in limbs.iter_mut().rev() {
+            reader.read_exact(&mut buf)?;
+            *limb = Limb(Word::from_be_bytes(buf));
+        }
+
+        Ok(Self::new(limbs))
+    }
+
+    /// Decode a [`Uint`] from exactly [`Uint::BYTES`] little-endian bytes read
+    /// from the provided [`Read`] implementation.
+    pub fn read_le<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut buf = [0u8; Limb::BYTES];
+
+        for limb in limbs.iter_mut() {
+            reader.read_exact(&mut buf)?;
+            *limb = Limb(Word::from_le_bytes(buf));
+        }
+
+        Ok(Self::new(limbs))
+    }
+
+    /// Decode a [`Uint`] from `len` big-endian bytes read from the provided
+    /// [`Read`] implementation, as written by [`Uint::write_be_minimal`].
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if `len`
+    /// exceeds [`Uint::BYTES`].
+    pub fn read_be_minimal<R: Read>(reader: &mut R, len: usize) -> io::Result<Self> {
+        if len > Self::BYTES {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "encoded integer is too large",
+            ));
+        }
+
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut byte = [0u8; 1];
+
+        for i in (0..len).rev() {
+            reader.read_exact(&mut byte)?;
+            limbs[i / Limb::BYTES].0 |= (byte[0] as Word) << ((i % Limb::BYTES) * 8);
+        }
+
+        Ok(Self::new(limbs))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{Encoding, U128};
+    use std::{io, vec::Vec};
+
+    const VALUE: U128 = U128::from_be_hex("00112233445566778899AABBCCDDEEFF");
+
+    #[test]
+    fn write_read_be() {
+        let mut buf = Vec::new();
+        VALUE.write_be(&mut buf).unwrap();
+        assert_eq!(buf, VALUE.to_be_bytes());
+        assert_eq!(U128::read_be(&mut buf.as_slice()).unwrap(), VALUE);
+    }
+
+    #[test]
+    fn write_read_le() {
+        let mut buf = Vec::new();
+        VALUE.write_le(&mut buf).unwrap();
+        assert_eq!(buf, VALUE.to_le_bytes());
+        assert_eq!(U128::read_le(&mut buf.as_slice()).unwrap(), VALUE);
+    }
+
+    #[test]
+    fn write_read_be_minimal() {
+        let mut buf = Vec::new();
+        let len = VALUE.write_be_minimal(&mut buf).unwrap();
+        assert_eq!(len, 15);
+        assert_eq!(buf, &VALUE.to_be_bytes()[1..]);
+        assert_eq!(
+            U128::read_be_minimal(&mut buf.as_slice(), len).unwrap(),
+            VALUE
+        );
+    }
+
+    #[test]
+    fn write_be_minimal_zero() {
+        let mut buf = Vec::new();
+        assert_eq!(U128::ZERO.write_be_minimal(&mut buf).unwrap(), 0);
+        assert!(buf.is_empty());
+        assert_eq!(
+            U128::read_be_minimal(&mut buf.as_slice(), 0).unwrap(),
+            U128::ZERO
+        );
+    }
+
+    #[test]
+    fn read_be_minimal_too_long() {
+        let buf = [0u8; 17];
+        let err = U128::read_be_minimal(&mut buf.as_slice(), 17).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_be_truncated() {
+        let buf = [0u8; 15];
+        let err = U128::read_be(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
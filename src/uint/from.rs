@@ -98,6 +98,52 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         limbs[1].0 = (n >> Limb::BITS) as Word;
         Self { limbs }
     }
+
+    /// Create a [`Uint`] from an array of `u32` words, least significant first (const-friendly).
+    ///
+    /// The result does not depend on the platform's `Word` size.
+    ///
+    /// Panics if `N * 32 != Self::BITS`.
+    #[allow(trivial_numeric_casts)]
+    pub const fn from_u32_array<const N: usize>(words: &[u32; N]) -> Self {
+        assert!(
+            N * 32 == Self::BITS,
+            "array size must match the integer size"
+        );
+        let mut limbs = [Limb::ZERO; LIMBS];
+
+        let mut i = 0;
+        while i < N {
+            let pos = i * 32;
+            limbs[pos / Limb::BITS].0 |= (words[i] as Word) << (pos % Limb::BITS);
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Serialize this [`Uint`] as an array of `u32` words, least significant first.
+    ///
+    /// The result does not depend on the platform's `Word` size.
+    ///
+    /// Panics if `N * 32 != Self::BITS`.
+    #[allow(trivial_numeric_casts)]
+    pub const fn to_u32_array<const N: usize>(&self) -> [u32; N] {
+        assert!(
+            N * 32 == Self::BITS,
+            "array size must match the integer size"
+        );
+        let mut words = [0u32; N];
+
+        let mut i = 0;
+        while i < N {
+            let pos = i * 32;
+            words[i] = (self.limbs[pos / Limb::BITS].0 >> (pos % Limb::BITS)) as u32;
+            i += 1;
+        }
+
+        words
+    }
 }
 
 impl<const LIMBS: usize> From<u8> for Uint<LIMBS> {
@@ -193,7 +239,7 @@ impl<const LIMBS: usize> From<Limb> for Uint<LIMBS> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Limb, Word, U128};
+    use crate::{Limb, Word, U128, U256};
 
     #[cfg(target_pointer_width = "32")]
     use crate::U64 as UintEx;
@@ -233,4 +279,33 @@ mod tests {
         let arr2: [Word; 2] = n.into();
         assert_eq!(arr1, arr2);
     }
+
+    #[test]
+    fn u32_array_round_trip() {
+        let words = [
+            0x8899aabb, 0xccddeeff, 0x00112233, 0x44556677, 0x01234567, 0x89abcdef, 0xdeadbeef,
+            0xfeedface,
+        ];
+        let n = U256::from_u32_array(&words);
+
+        // Word order is least significant first regardless of the limb size
+        assert_eq!(
+            n,
+            U256::from_be_hex("feedfacedeadbeef89abcdef012345674455667700112233ccddeeff8899aabb")
+        );
+        assert_eq!(n.to_u32_array(), words);
+    }
+
+    #[test]
+    fn u32_array_const() {
+        const N: U128 = U128::from_u32_array(&[1, 2, 3, 4]);
+        assert_eq!(N, U128::from_be_hex("00000004000000030000000200000001"));
+        assert_eq!(N.to_u32_array::<4>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "array size must match the integer size")]
+    fn u32_array_wrong_size() {
+        let _ = U128::ONE.to_u32_array::<3>();
+    }
 }
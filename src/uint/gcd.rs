@@ -0,0 +1,164 @@
+//! [`Uint`] greatest common divisor operations.
+
+use super::Uint;
+use crate::{Limb, NonZero, Word};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes the greatest common divisor of `self` and `rhs` using the binary GCD algorithm.
+    ///
+    /// Runs in constant time: the number of iterations only depends on `LIMBS`,
+    /// so this is suitable for secret inputs. `gcd(0, 0)` is defined as `0`.
+    pub const fn gcd(&self, rhs: &Self) -> Self {
+        // `gcd(0, b) = gcd(b, b)`, which lets us assume both inputs are nonzero
+        // (unless both of them are zero, in which case the result is zero anyway).
+        let mut a = Self::ct_select(rhs, self, self.ct_is_nonzero());
+        let mut b = Self::ct_select(self, rhs, rhs.ct_is_nonzero());
+
+        // Remove the common powers of two
+        let mut k: Word = 0;
+        let mut i = 0;
+        while i < Self::BITS {
+            let both_even = a.ct_is_odd().or(b.ct_is_odd()).not().and(a.ct_is_nonzero());
+            a = Self::ct_select(&a, &a.shr_vartime(1), both_even);
+            b = Self::ct_select(&b, &b.shr_vartime(1), both_even);
+            k += both_even.if_true(1);
+            i += 1;
+        }
+
+        // Make `a` odd
+        let mut i = 0;
+        while i < Self::BITS {
+            let is_even = a.ct_is_odd().not().and(a.ct_is_nonzero());
+            a = Self::ct_select(&a, &a.shr_vartime(1), is_even);
+            i += 1;
+        }
+
+        // Invariant: `a` is odd (or both `a` and `b` are zero).
+        // Every two iterations reduce `bits(a) + bits(b)` by at least one.
+        let mut i = 0;
+        while i < 4 * Self::BITS {
+            let b_is_odd = b.ct_is_odd();
+
+            // If `b` is odd, replace `(a, b)` with `(min(a, b), |a - b|)`, which makes `b` even
+            let swap = Self::ct_lt(&b, &a).and(b_is_odd);
+            let (new_a, new_b) = (Self::ct_select(&a, &b, swap), Self::ct_select(&b, &a, swap));
+            a = new_a;
+            b = Self::ct_select(&new_b, &new_b.wrapping_sub(&new_a), b_is_odd);
+
+            // If `b` is even, halve it
+            b = Self::ct_select(&b.shr_vartime(1), &b, b_is_odd);
+            i += 1;
+        }
+
+        // Restore the common powers of two
+        let mut i = 0;
+        while i < Self::BITS {
+            let in_range = Limb::ct_lt(Limb(i as Word), Limb(k));
+            a = Self::ct_select(&a, &a.shl_vartime(1), in_range);
+            i += 1;
+        }
+
+        a
+    }
+
+    /// Computes the greatest common divisor of `self` and `rhs` using the Euclidean algorithm.
+    ///
+    /// This is usually much faster than [`Uint::gcd`], since it terminates as soon as
+    /// the remainder becomes zero. `gcd(0, 0)` is defined as `0`.
+    ///
+    /// NOTE: this operation is variable time with respect to both `self` and `rhs`,
+    /// so it must only be used with public inputs.
+    pub const fn gcd_vartime(&self, rhs: &Self) -> Self {
+        let mut a = *self;
+        let mut b = *rhs;
+        while b.ct_is_nonzero().is_true_vartime() {
+            let r = a.const_rem(&b).0;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Reduces the fraction `a / b` to lowest terms, returning `(a / g, b / g)`
+    /// where `g = gcd(a, b)`.
+    ///
+    /// The GCD is computed once with [`Uint::gcd`]. If `a` is zero, the result is `(0, 1)`.
+    ///
+    /// NOTE: the divisions are variable time with respect to `g`.
+    pub fn reduce_fraction(a: &Self, b: &NonZero<Self>) -> (Self, Self) {
+        // `g` is nonzero since `b` is
+        let g = a.gcd(b);
+        let (a_reduced, _, _) = a.ct_div_rem(&g);
+        let (b_reduced, _, _) = b.ct_div_rem(&g);
+        (a_reduced, b_reduced)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonZero, Uint, U128, U256};
+
+    #[test]
+    fn gcd_small() {
+        let cases: [(u64, u64, u64); 9] = [
+            (0, 0, 0),
+            (0, 5, 5),
+            (5, 0, 5),
+            (1, 1, 1),
+            (12, 18, 6),
+            (18, 12, 6),
+            (17, 31, 1),
+            (48, 64, 16),
+            (1 << 40, 1 << 20, 1 << 20),
+        ];
+
+        for (a, b, expected) in cases {
+            let (a, b, expected) = (U128::from(a), U128::from(b), U128::from(expected));
+            assert_eq!(a.gcd(&b), expected);
+            assert_eq!(a.gcd_vartime(&b), expected);
+        }
+    }
+
+    #[test]
+    fn gcd_large() {
+        let g =
+            U256::from_be_hex("00000000000000000000000000000000f3b9cac2fc632551bce6faada7179e85");
+        let x =
+            U256::from_be_hex("000000000000000000000000000000000000000000000000ffffffff00000001");
+        let y =
+            U256::from_be_hex("00000000000000000000000000000000000000000000000000000000fffffffb");
+        let (a, b) = (g.wrapping_mul(&x), g.wrapping_mul(&y));
+
+        assert_eq!(a.gcd(&b), g);
+        assert_eq!(a.gcd_vartime(&b), g);
+        assert_eq!(U256::MAX.gcd(&U256::MAX), U256::MAX);
+        assert_eq!(U256::MAX.gcd_vartime(&U256::MAX), U256::MAX);
+    }
+
+    #[test]
+    fn reduce_fraction() {
+        let cases: [(u64, u64, u64, u64); 6] = [
+            (0, 7, 0, 1),
+            (0, 1, 0, 1),
+            (6, 4, 3, 2),
+            (17, 31, 17, 31),
+            (48, 64, 3, 4),
+            (1 << 40, 1 << 20, 1 << 20, 1),
+        ];
+
+        for (a, b, expected_a, expected_b) in cases {
+            let b = NonZero::new(U128::from(b)).unwrap();
+            assert_eq!(
+                U128::reduce_fraction(&U128::from(a), &b),
+                (U128::from(expected_a), U128::from(expected_b))
+            );
+        }
+
+        let g =
+            U256::from_be_hex("00000000000000000000000000000000f3b9cac2fc632551bce6faada7179e85");
+        let x = U256::from_u64(0xffff_ffff_0000_0001);
+        let y = U256::from_u64(0xffff_fffb);
+        let b = NonZero::new(g.wrapping_mul(&y)).unwrap();
+        assert_eq!(Uint::reduce_fraction(&g.wrapping_mul(&x), &b), (x, y));
+    }
+}
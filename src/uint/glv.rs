@@ -0,0 +1,139 @@
+//! [`Uint`] scalar decomposition for the GLV endomorphism.
+
+use super::Uint;
+use crate::{Concat, NonZero, Split};
+use subtle::{Choice, ConditionallySelectable};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Splits the scalar `self` into two roughly half-width components `(k1, k2)` such that
+    /// `k1 + k2 * lambda ≡ self (mod order)`, as used by GLV scalar multiplication.
+    ///
+    /// `basis` is the precomputed reduced lattice basis `[(a1, b1), (a2, b2)]` of vectors
+    /// satisfying `a + b * lambda ≡ 0 (mod order)`, whose signed entries are given in
+    /// two's complement. The components are returned as a magnitude along with a [`Choice`]
+    /// which is truthy if they are negative, as in [`Uint::as_signed`].
+    ///
+    /// The components are computed with Babai's rounding: `c1 = round(b2 * k / order)` and
+    /// `c2 = round(-b1 * k / order)`, and then `k1 = k - c1 * a1 - c2 * a2` and
+    /// `k2 = -c1 * b1 - c2 * b2`. Their magnitudes must be less than `2^(BITS - 1)`,
+    /// which holds for any reduced basis.
+    ///
+    /// This is variable only with respect to `order`.
+    pub fn glv_decompose<const WIDE_LIMBS: usize>(
+        &self,
+        basis: &[(Self, Self); 2],
+        order: &NonZero<Self>,
+    ) -> ((Self, Choice), (Self, Choice))
+    where
+        Self: Concat<Output = Uint<WIDE_LIMBS>>,
+        Uint<WIDE_LIMBS>: Split<Output = Self>,
+    {
+        let k = self.rem(order);
+        let [(a1, b1), (a2, b2)] = basis;
+
+        // `round(b * k / order)`, with `b * k + floor(order / 2)` computed in double width
+        let half_order = Concat::concat(&Self::ZERO, &order.shr_vartime(1));
+        let round_div = |b: &Self| {
+            let (lo, hi) = b.mul_wide(&k);
+            let (quo, _) = Concat::concat(&hi, &lo)
+                .wrapping_add(&half_order)
+                .div_rem_wide(order);
+            let (_, quo) = quo.split();
+            quo
+        };
+
+        let (b1_abs, b1_is_negative) = b1.as_signed(Self::BITS - 1);
+        let (b2_abs, b2_is_negative) = b2.as_signed(Self::BITS - 1);
+        let c1 = to_twos_complement(&round_div(&b2_abs), b2_is_negative);
+        let c2 = to_twos_complement(&round_div(&b1_abs), !b1_is_negative);
+
+        // All the values fit in `BITS - 1` bits, so wrapping arithmetic is exact
+        let k1 = k
+            .wrapping_sub(&c1.wrapping_mul(a1))
+            .wrapping_sub(&c2.wrapping_mul(a2));
+        let k2 = Self::ZERO
+            .wrapping_sub(&c1.wrapping_mul(b1))
+            .wrapping_sub(&c2.wrapping_mul(b2));
+
+        (k1.as_signed(Self::BITS - 1), k2.as_signed(Self::BITS - 1))
+    }
+}
+
+/// Converts a magnitude and sign to the two's complement representation of the value.
+fn to_twos_complement<const LIMBS: usize>(
+    magnitude: &Uint<LIMBS>,
+    is_negative: Choice,
+) -> Uint<LIMBS> {
+    Uint::conditional_select(magnitude, &Uint::ZERO.wrapping_sub(magnitude), is_negative)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        NonZero, U256,
+    };
+    use subtle::Choice;
+
+    // secp256k1 group order
+    const N: U256 =
+        U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+    // A cube root of unity mod `N`
+    const LAMBDA: U256 =
+        U256::from_be_hex("5363AD4CC05C30E0A5261C028812645A122E22EA20816678DF02967C1B23BD72");
+    const A1: U256 =
+        U256::from_be_hex("000000000000000000000000000000003086D221A7D46BCDE86C90E49284EB15");
+    const B1: U256 =
+        U256::from_be_hex("00000000000000000000000000000000E4437ED6010E88286F547FA90ABFE4C3");
+    const A2: U256 =
+        U256::from_be_hex("0000000000000000000000000000000114CA50F7A8E2F3F657C1108D9D44CFD8");
+
+    fn basis() -> [(U256, U256); 2] {
+        // `b1` is negative
+        [(A1, U256::ZERO.wrapping_sub(&B1)), (A2, A1)]
+    }
+
+    fn to_residue((magnitude, is_negative): (U256, Choice)) -> DynResidue<{ U256::LIMBS }> {
+        let x = DynResidue::new(&magnitude, DynResidueParams::new(&N));
+        if is_negative.into() {
+            -x
+        } else {
+            x
+        }
+    }
+
+    #[test]
+    fn glv_decompose_secp256k1() {
+        let order = NonZero::new(N).unwrap();
+        let params = DynResidueParams::new(&N);
+        let lambda = DynResidue::new(&LAMBDA, params);
+
+        let scalars = [
+            U256::ZERO,
+            U256::ONE,
+            LAMBDA,
+            N.wrapping_sub(&U256::ONE),
+            U256::from_be_hex("E4437ED6010E88286F547FA90ABFE4C3E4437ED6010E88286F547FA90ABFE4C3"),
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+        ];
+
+        for k in scalars {
+            let (k1, k2) = k.glv_decompose(&basis(), &order);
+
+            // Both components are at most 129 bits long
+            assert!(k1.0.bits() <= 129);
+            assert!(k2.0.bits() <= 129);
+
+            let recombined = to_residue(k1) + to_residue(k2) * lambda;
+            assert_eq!(recombined.retrieve(), k);
+        }
+    }
+
+    #[test]
+    fn glv_decompose_lambda() {
+        // `lambda = 0 + 1 * lambda`
+        let (k1, k2) = LAMBDA.glv_decompose(&basis(), &NonZero::new(N).unwrap());
+        assert_eq!(to_residue(k1).retrieve(), U256::ZERO);
+        assert_eq!(to_residue(k2).retrieve(), U256::ONE);
+    }
+}
@@ -1,5 +1,6 @@
 use super::Uint;
-use crate::{CtChoice, Limb};
+use crate::{CtChoice, Limb, Word};
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes 1/`self` mod 2^k as specified in Algorithm 4 from
@@ -101,11 +102,42 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     pub const fn inv_odd_mod(&self, modulus: &Self) -> (Self, CtChoice) {
         self.inv_odd_mod_bounded(modulus, Uint::<LIMBS>::BITS, Uint::<LIMBS>::BITS)
     }
+
+    /// Computes the multiplicative inverse of `self` mod `modulus`, for any nonzero `modulus`
+    /// (unlike [`Uint::inv_odd_mod`], it may be even).
+    /// Returns none if `modulus` is zero or if `gcd(self, modulus) != 1`.
+    ///
+    /// The modulus is decomposed as `s * 2^k` with `s` odd, the inverses mod `s` and mod `2^k`
+    /// are computed separately, and combined with one step of Garner's algorithm.
+    ///
+    /// **Note:** variable time in the number of trailing zeros of `modulus`.
+    pub fn inv_mod(&self, modulus: &Self) -> CtOption<Self> {
+        let is_nonzero = modulus.ct_is_nonzero();
+
+        // Decompose `modulus = s * 2^k` where `s` is odd (using `s = 1` for a zero modulus)
+        let k = modulus.trailing_zeros();
+        let s = Self::ct_select(&Self::ONE, &modulus.shr_vartime(k), is_nonzero);
+
+        // `(z^-1 mod (m1 * m2)) mod m1 == z^-1 mod m1`, so these are the residues of the result
+        let (a, a_is_some) = self.inv_odd_mod(&s);
+        let b = self.inv_mod2k(k);
+        // The inverse mod `2^k` exists if `k == 0` or if `self` is odd
+        let b_is_some = Limb(k as Word).ct_is_nonzero().not().or(self.ct_is_odd());
+
+        // Recombine: `self^-1 = a + s * ((b - a) * s^-1 mod 2^k)`, which is at most
+        // `(s - 1) + s * (2^k - 1) = modulus - 1`, so it cannot overflow
+        let s_inv = s.inv_mod2k(k);
+        let mask = Self::ONE.shl_vartime(k).wrapping_sub(&Self::ONE);
+        let t = b.wrapping_sub(&a).wrapping_mul(&s_inv).bitand(&mask);
+        let result = a.wrapping_add(&s.wrapping_mul(&t));
+
+        CtOption::new(result, is_nonzero.and(a_is_some).and(b_is_some).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{U1024, U256, U64};
+    use crate::{U1024, U128, U256, U64};
 
     #[test]
     fn inv_mod2k() {
@@ -198,4 +230,53 @@ mod tests {
 
         assert!(!is_some.is_true_vartime());
     }
+
+    #[test]
+    fn test_inv_mod_even() {
+        // 3 * 11 = 33 = 1 mod 16
+        let res = U64::from(3u8).inv_mod(&U64::from(16u8));
+        assert_eq!(res.unwrap(), U64::from(11u8));
+
+        // 7 * 103 = 721 = 1 mod 240
+        let res = U64::from(7u8).inv_mod(&U64::from(240u8));
+        assert_eq!(res.unwrap(), U64::from(103u8));
+
+        // Unreduced input
+        let res = U64::from(247u8).inv_mod(&U64::from(240u8));
+        assert_eq!(res.unwrap(), U64::from(103u8));
+
+        // Full width modulus with a large power of two factor
+        let m = U128::from_be_hex("d198d3155e5799dc4ea7665200000000");
+        let x = U128::from_be_hex("0123456789abcdef0123456789abcdef");
+        let inv = x.inv_mod(&m).unwrap();
+        assert!(inv < m);
+        assert_eq!(U128::const_rem_wide(x.mul_wide(&inv), &m).0, U128::ONE);
+    }
+
+    #[test]
+    fn test_inv_mod_matches_odd() {
+        let m =
+            U256::from_be_hex("9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B");
+        let x =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+
+        let (expected, is_some) = x.inv_odd_mod(&m);
+        assert!(is_some.is_true_vartime());
+        assert_eq!(x.inv_mod(&m).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_inv_mod_none() {
+        // Even numbers have no inverse mod an even modulus
+        assert!(bool::from(
+            U64::from(6u8).inv_mod(&U64::from(16u8)).is_none()
+        ));
+        // Common odd factor
+        assert!(bool::from(
+            U64::from(9u8).inv_mod(&U64::from(24u8)).is_none()
+        ));
+        assert!(bool::from(U64::ZERO.inv_mod(&U64::from(24u8)).is_none()));
+        // Zero modulus
+        assert!(bool::from(U64::ONE.inv_mod(&U64::ZERO).is_none()));
+    }
 }
@@ -26,9 +26,12 @@ pub trait Retrieve {
 #[cfg(test)]
 mod tests {
     use crate::{
-        const_residue, impl_modulus,
+        const_residue, define_residue, impl_modulus,
         modular::{
-            constant_mod::Residue, constant_mod::ResidueParams, reduction::montgomery_reduction,
+            constant_mod::Residue,
+            constant_mod::ResidueParams,
+            reduction::montgomery_reduction,
+            runtime_mod::{DynResidue, DynResidueParams},
         },
         NonZero, Uint, U256, U64,
     };
@@ -160,4 +163,159 @@ mod tests {
             const_residue!(x, Modulus2)
         );
     }
+
+    define_residue!(
+        Fp,
+        modulus = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+
+    #[test]
+    fn test_define_residue_params() {
+        assert_eq!(Fp::LIMBS, U256::LIMBS);
+        assert_eq!(Fp::MODULUS, Modulus1::MODULUS);
+        assert_eq!(Fp::R, Modulus1::R);
+        assert_eq!(Fp::R2, Modulus1::R2);
+        assert_eq!(Fp::R3, Modulus1::R3);
+        assert_eq!(Fp::MOD_NEG_INV, Modulus1::MOD_NEG_INV);
+    }
+
+    #[test]
+    fn test_define_residue_matches_dyn_residue() {
+        let params = DynResidueParams::new(&Fp::MODULUS);
+
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let y =
+            U256::from_be_hex("0d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a25");
+
+        let x_const = const_residue!(x, Fp);
+        let y_const = const_residue!(y, Fp);
+        let x_dyn = DynResidue::new(&x, params);
+        let y_dyn = DynResidue::new(&y, params);
+
+        assert_eq!(x_const.retrieve(), x_dyn.retrieve());
+        assert_eq!((x_const + y_const).retrieve(), (x_dyn + y_dyn).retrieve());
+        assert_eq!((x_const - y_const).retrieve(), (x_dyn - y_dyn).retrieve());
+        assert_eq!((x_const * y_const).retrieve(), (x_dyn * y_dyn).retrieve());
+        assert_eq!(x_const.pow(&y).retrieve(), x_dyn.pow(&y).retrieve());
+        assert_eq!(x_const.invert().0.retrieve(), x_dyn.invert().0.retrieve());
+    }
+
+    #[test]
+    fn test_from_uint_reduced() {
+        let modulus = NonZero::new(Modulus2::MODULUS).unwrap();
+        let params = DynResidueParams::new(&Modulus2::MODULUS);
+
+        for x in [
+            U256::ZERO,
+            Modulus2::MODULUS.wrapping_sub(&U256::ONE),
+            Modulus2::MODULUS,
+            Modulus2::MODULUS.wrapping_add(&U256::from(5u8)),
+            U256::MAX,
+        ] {
+            let expected = x.rem(&modulus);
+
+            let x_mod = Residue::<Modulus2, { Modulus2::LIMBS }>::from_uint_reduced(x);
+            assert_eq!(x_mod.retrieve(), expected);
+            assert_eq!(x_mod, Residue::new(&expected));
+
+            let x_dyn = DynResidue::from_uint_reduced(x, params);
+            assert_eq!(x_dyn.retrieve(), expected);
+            assert_eq!(x_dyn, DynResidue::new(&expected, params));
+        }
+    }
+
+    #[test]
+    fn test_from_wide() {
+        let params = DynResidueParams::new(&Modulus2::MODULUS);
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let y =
+            U256::from_be_hex("0d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a25");
+
+        for (a, b) in [
+            (x, y),
+            (U256::ZERO, y),
+            (U256::ONE, U256::ONE),
+            (
+                Modulus2::MODULUS.wrapping_sub(&U256::ONE),
+                Modulus2::MODULUS.wrapping_sub(&U256::ONE),
+            ),
+        ] {
+            let wide = a.mul_wide(&b);
+
+            let expected = const_residue!(a, Modulus2) * const_residue!(b, Modulus2);
+            assert_eq!(
+                Residue::<Modulus2, { Modulus2::LIMBS }>::from_wide(wide),
+                expected
+            );
+
+            let expected = DynResidue::new(&a, params) * DynResidue::new(&b, params);
+            assert_eq!(DynResidue::from_wide(wide, params), expected);
+        }
+    }
+
+    #[test]
+    fn test_operator_formulas() {
+        let params = DynResidueParams::new(&Fp::MODULUS);
+
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let y =
+            U256::from_be_hex("0d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a25");
+        let a = U256::from(3u8);
+
+        let (x_const, y_const, a_const) = (
+            const_residue!(x, Fp),
+            const_residue!(y, Fp),
+            const_residue!(a, Fp),
+        );
+        let z_const = x_const * y_const + a_const - -x_const;
+        assert_eq!(
+            z_const,
+            x_const.mul(&y_const).add(&a_const).sub(&x_const.neg())
+        );
+
+        let mut w_const = x_const;
+        w_const *= y_const;
+        w_const += &a_const;
+        w_const -= -x_const;
+        assert_eq!(w_const, z_const);
+
+        let (x_dyn, y_dyn, a_dyn) = (
+            DynResidue::new(&x, params),
+            DynResidue::new(&y, params),
+            DynResidue::new(&a, params),
+        );
+        let z_dyn = x_dyn * y_dyn + a_dyn - -x_dyn;
+        assert_eq!(z_dyn, x_dyn.mul(&y_dyn).add(&a_dyn).sub(&x_dyn.neg()));
+
+        let mut w_dyn = x_dyn;
+        w_dyn *= &y_dyn;
+        w_dyn += a_dyn;
+        w_dyn -= -x_dyn;
+        assert_eq!(w_dyn, z_dyn);
+
+        assert_eq!(z_const.retrieve(), z_dyn.retrieve());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_dyn_residue_mixed_moduli() {
+        let x = DynResidue::new(&U256::ONE, DynResidueParams::new(&Fp::MODULUS));
+        let y = DynResidue::new(&U256::ONE, DynResidueParams::new(&Modulus2::MODULUS));
+        let _ = x + y;
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_residue_try_random() {
+        use crate::Random;
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let x = Residue::<Modulus2, { Modulus2::LIMBS }>::try_random(&mut rng);
+        assert!(matches!(x, Ok(x) if x.retrieve() < Modulus2::MODULUS));
+    }
 }
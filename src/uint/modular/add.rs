@@ -7,3 +7,22 @@ pub(crate) const fn add_montgomery_form<const LIMBS: usize>(
 ) -> Uint<LIMBS> {
     a.add_mod(b, modulus)
 }
+
+/// Computes `a * 2^k mod modulus` by performing `k` modular doublings.
+///
+/// NOTE: this operation is variable time with respect to `k` *ONLY*.
+pub(crate) const fn shl_montgomery_form<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    k: usize,
+    modulus: &Uint<LIMBS>,
+) -> Uint<LIMBS> {
+    let mut result = *a;
+    let mut i = 0;
+
+    while i < k {
+        result = result.add_mod(&result, modulus);
+        i += 1;
+    }
+
+    result
+}
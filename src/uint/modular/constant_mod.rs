@@ -1,10 +1,17 @@
 use core::{fmt::Debug, marker::PhantomData};
 
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::{Limb, Uint, Zero};
+use crate::{Field, Limb, Reciprocal, Uint, Zero};
 
-use super::{reduction::montgomery_reduction, Retrieve};
+use super::{
+    mul::{modulus_leading_reciprocal, mul_montgomery_form},
+    reduction::montgomery_reduction,
+    Retrieve,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "rand_core")]
 use crate::{rand_core::CryptoRngCore, NonZero, Random, RandomMod};
@@ -24,6 +31,8 @@ mod const_inv;
 mod const_mul;
 /// Negations of residues with a constant modulus
 mod const_neg;
+/// Bit-reversal permutation of residues with a constant modulus for number-theoretic transforms
+mod const_ntt;
 /// Exponentiation of residues with a constant modulus
 mod const_pow;
 /// Subtractions between residues with a constant modulus
@@ -33,8 +42,6 @@ mod const_sub;
 #[macro_use]
 mod macros;
 
-pub use macros::*;
-
 /// The parameters to efficiently go to and from the Montgomery form for a given odd modulus. An easy way to generate these parameters is using the `impl_modulus!` macro. These parameters are constant, so they cannot be set at runtime.
 ///
 /// Unfortunately, `LIMBS` must be generic for now until const generics are stabilized.
@@ -55,10 +62,29 @@ pub trait ResidueParams<const LIMBS: usize>:
     /// The lowest limbs of -(MODULUS^-1) mod R
     // We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
     const MOD_NEG_INV: Limb;
+    /// MODULUS - 2, the exponent used to invert via Fermat's little theorem
+    const MODULUS_MINUS_TWO: Uint<LIMBS> = Self::MODULUS.wrapping_sub(&Uint::from_u8(2));
+    /// Number of leading zeros of MODULUS, used to multiply by a single limb
+    const MOD_LEADING_ZEROS: usize = Self::MODULUS.leading_zeros();
+    /// MODULUS shifted left by `MOD_LEADING_ZEROS`, so that its top bit is set
+    const MODULUS_NORMALIZED: Uint<LIMBS> = Self::MODULUS.shl_vartime(Self::MOD_LEADING_ZEROS);
+    /// Reciprocal of the top limb of the normalized modulus, used to multiply by a single limb
+    const MOD_LEADING_RECIPROCAL: Reciprocal =
+        modulus_leading_reciprocal(&Self::MODULUS_NORMALIZED);
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// A residue mod `MOD`, represented using `LIMBS` limbs. The modulus of this residue is constant, so it cannot be set at runtime.
+///
+/// The arithmetic operators (`+`, `-`, `*`, unary `-` and their assigning versions) perform
+/// the corresponding operations mod `MOD`. Since the modulus is part of the type, residues with
+/// different moduli cannot be mixed.
+///
+/// The Montgomery form is always kept fully reduced, i.e. less than `MOD`, by every
+/// constructor and operation. Since the Montgomery transform is a bijection on the reduced
+/// values, each residue has a unique representation, and equality (`==` as well as
+/// [`ConstantTimeEq::ct_eq`]) compares the represented values regardless of how the
+/// residues were obtained.
 pub struct Residue<MOD, const LIMBS: usize>
 where
     MOD: ResidueParams<LIMBS>,
@@ -81,6 +107,9 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
     };
 
     /// Instantiates a new `Residue` that represents this `integer` mod `MOD`.
+    ///
+    /// `integer` does not need to be reduced: any value is reduced mod `MOD`
+    /// as part of the conversion into Montgomery form.
     pub const fn new(integer: &Uint<LIMBS>) -> Self {
         let product = integer.mul_wide(&MOD::R2);
         let montgomery_form =
@@ -100,6 +129,120 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             MOD::MOD_NEG_INV,
         )
     }
+
+    /// Returns the internal Montgomery form of this residue, i.e. `self * R mod MOD`,
+    /// for interoperability with other implementations using the same representation.
+    pub const fn as_montgomery(&self) -> &Uint<LIMBS> {
+        &self.montgomery_form
+    }
+
+    /// Creates a residue directly from its Montgomery form `value = x * R mod MOD`,
+    /// without any conversion.
+    ///
+    /// **WARNING**: this is an escape hatch for interoperability. The caller must
+    /// guarantee that `value` is a valid Montgomery representative for this same modulus,
+    /// i.e. that it was computed with the same `R` (`2^Uint::<LIMBS>::BITS`) and is fully
+    /// reduced (less than `MOD`). Otherwise all the operations on the resulting residue,
+    /// including equality, silently produce wrong results. Only the reduction is checked,
+    /// and only by a debug assertion.
+    pub const fn from_montgomery_raw(value: Uint<LIMBS>) -> Self {
+        debug_assert!(
+            Uint::ct_lt(&value, &MOD::MODULUS).is_true_vartime(),
+            "Montgomery form must be reduced"
+        );
+        Self {
+            montgomery_form: value,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Retrieves the integers encoded in each of the `values`, guaranteed to be reduced.
+    ///
+    /// This is equivalent to calling [`Residue::retrieve`] on each of them.
+    pub const fn retrieve_array<const N: usize>(values: &[Self; N]) -> [Uint<LIMBS>; N] {
+        let mut out = [Uint::ZERO; N];
+        let mut i = 0;
+        while i < N {
+            out[i] = values[i].retrieve();
+            i += 1;
+        }
+        out
+    }
+
+    /// Retrieves the integers encoded in each of the `values`, guaranteed to be reduced.
+    ///
+    /// This is equivalent to calling [`Residue::retrieve`] on each of them.
+    #[cfg(feature = "alloc")]
+    pub fn retrieve_batch(values: &[Self]) -> Vec<Uint<LIMBS>> {
+        values.iter().map(Self::retrieve).collect()
+    }
+
+    /// Converts this residue into one modulo `MOD2`, going through the canonical value
+    /// `x` of `self`, i.e. the result represents `x mod MOD2`.
+    ///
+    /// `x` is reduced again if it is not less than `MOD2`; see [`Residue::checked_cast_to`]
+    /// for a variant rejecting such values instead.
+    pub const fn cast_to<MOD2: ResidueParams<LIMBS>>(&self) -> Residue<MOD2, LIMBS> {
+        Residue::new(&self.retrieve())
+    }
+
+    /// Converts this residue into one modulo `MOD2`, going through the canonical value
+    /// `x` of `self`, returning none if `x` is greater than or equal to `MOD2`, i.e. if
+    /// it would be changed by the conversion.
+    ///
+    /// The check is performed in constant time.
+    pub fn checked_cast_to<MOD2: ResidueParams<LIMBS>>(&self) -> CtOption<Residue<MOD2, LIMBS>> {
+        let integer = self.retrieve();
+        CtOption::new(
+            Residue::new(&integer),
+            Uint::ct_lt(&integer, &MOD2::MODULUS).into(),
+        )
+    }
+
+    /// Instantiates a new `Residue` that represents `value` mod `MOD`, where
+    /// `value` may be greater than or equal to `MOD`.
+    ///
+    /// This is equivalent to [`Residue::new`], and exists to make the
+    /// reduction of arbitrary inputs explicit at the call site.
+    pub const fn from_uint_reduced(value: Uint<LIMBS>) -> Self {
+        Self::new(&value)
+    }
+
+    /// Instantiates a new `Residue` that represents the double-width value `lo + hi * 2^BITS`
+    /// mod `MOD`, given as the `(lo, hi)` pair returned by [`Uint::mul_wide`].
+    ///
+    /// The value is Montgomery-reduced directly and then moved into Montgomery form with a
+    /// single multiplication by `R^3`, without first reducing it with a wide division.
+    ///
+    /// `hi` must be less than `MOD`, which always holds for the product of two reduced values.
+    /// This is only checked by a debug assertion.
+    pub const fn from_wide(value: (Uint<LIMBS>, Uint<LIMBS>)) -> Self {
+        debug_assert!(
+            Uint::ct_lt(&value.1, &MOD::MODULUS).is_true_vartime(),
+            "high half must be less than the modulus"
+        );
+        let reduced = montgomery_reduction::<LIMBS>(&value, &MOD::MODULUS, MOD::MOD_NEG_INV);
+        let montgomery_form =
+            mul_montgomery_form(&reduced, &MOD::R3, &MOD::MODULUS, MOD::MOD_NEG_INV);
+
+        Self {
+            montgomery_form,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Decodes a `Residue` from the big endian encoding of its canonical value, returning
+    /// none if the value is not reduced, i.e. if it is greater than or equal to `MOD`.
+    ///
+    /// Rejecting non-canonical encodings guarantees that every residue has a unique
+    /// encoding. The check is performed in constant time.
+    ///
+    /// Panics if `bytes` is not `Uint::<LIMBS>::BYTES` long.
+    pub fn from_bytes_canonical(bytes: &[u8]) -> CtOption<Self> {
+        let value = Uint::from_be_slice(bytes);
+        let is_reduced = Uint::ct_lt(&value, &MOD::MODULUS);
+        CtOption::new(Self::new(&value), is_reduced.into())
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS> + Copy, const LIMBS: usize> ConditionallySelectable
@@ -118,6 +261,8 @@ impl<MOD: ResidueParams<LIMBS> + Copy, const LIMBS: usize> ConditionallySelectab
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> ConstantTimeEq for Residue<MOD, LIMBS> {
+    /// Compares the Montgomery forms directly, which is correct because they are always
+    /// reduced: see [`Residue`].
     fn ct_eq(&self, other: &Self) -> Choice {
         ConstantTimeEq::ct_eq(&self.montgomery_form, &other.montgomery_form)
     }
@@ -133,15 +278,50 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Zero for Residue<MOD, LIMBS>
     const ZERO: Self = Self::ZERO;
 }
 
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Field for Residue<MOD, LIMBS> {
+    type Integer = Uint<LIMBS>;
+
+    const ONE: Self = Self::ONE;
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let (value, is_some) = self.invert();
+        CtOption::new(value, is_some.into())
+    }
+
+    fn pow(&self, exponent: &Uint<LIMBS>) -> Self {
+        self.pow(exponent)
+    }
+}
+
 #[cfg(feature = "rand_core")]
 impl<MOD, const LIMBS: usize> Random for Residue<MOD, LIMBS>
 where
     MOD: ResidueParams<LIMBS>,
 {
+    /// Generate a cryptographically secure random element of the field `[0, MODULUS)`,
+    /// returned in Montgomery form.
+    ///
+    /// This uses rejection sampling via [`Uint::random_mod`], so there is no bias from the
+    /// uniform distribution (assuming an unbiased CSRNG). Candidates are masked to the bit
+    /// length of the modulus, so each round succeeds with probability greater than 1/2
+    /// and the expected number of rounds is less than two, but the running time is variable.
     #[inline]
     fn random(rng: &mut impl CryptoRngCore) -> Self {
         Self::new(&Uint::random_mod(rng, &NonZero::from_uint(MOD::MODULUS)))
     }
+
+    #[inline]
+    fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        Uint::try_random_mod(rng, &NonZero::from_uint(MOD::MODULUS)).map(|x| Self::new(&x))
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Retrieve for Residue<MOD, LIMBS> {
@@ -157,18 +337,21 @@ where
     MOD: ResidueParams<LIMBS>,
     Uint<LIMBS>: Encoding,
 {
+    /// Deserializes the canonical (retrieved) value of the residue and converts it to
+    /// Montgomery form. Values which are not reduced modulo `MOD::MODULUS` are rejected.
+    ///
+    /// NOTE: v0.5 serialized the Montgomery form instead, which this does not accept.
+    /// Such data can be migrated by deserializing a [`Uint`] and passing it to
+    /// [`Residue::from_montgomery_raw`].
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Uint::<LIMBS>::deserialize(deserializer).and_then(|montgomery_form| {
-            if Uint::ct_lt(&montgomery_form, &MOD::MODULUS).into() {
-                Ok(Self {
-                    montgomery_form,
-                    phantom: PhantomData,
-                })
+        Uint::<LIMBS>::deserialize(deserializer).and_then(|value| {
+            if Uint::ct_lt(&value, &MOD::MODULUS).into() {
+                Ok(Self::new(&value))
             } else {
-                Err(D::Error::custom("montgomery form must be reduced"))
+                Err(D::Error::custom("residue value must be reduced"))
             }
         })
     }
@@ -180,10 +363,271 @@ where
     MOD: ResidueParams<LIMBS>,
     Uint<LIMBS>: Encoding,
 {
+    /// Serializes the canonical (retrieved) value of the residue rather than its
+    /// Montgomery form, so the encoding does not depend on the internal representation.
+    ///
+    /// NOTE: this differs from the format of v0.5, which serialized the Montgomery form.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        self.montgomery_form.serialize(serializer)
+        self.retrieve().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Residue;
+    use crate::{modular::constant_mod::ResidueParams, Encoding, Field, U256};
+    use subtle::ConstantTimeEq;
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+    );
+
+    type Fe = Residue<Modulus, { U256::LIMBS }>;
+
+    const X: U256 =
+        U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+    #[test]
+    fn from_bytes_canonical() {
+        let x = Fe::from_bytes_canonical(&X.to_be_bytes());
+        assert_eq!(x.unwrap(), Fe::new(&X));
+
+        let max = Modulus::MODULUS.wrapping_sub(&U256::ONE);
+        let x = Fe::from_bytes_canonical(&max.to_be_bytes());
+        assert_eq!(x.unwrap(), -Fe::ONE);
+
+        for value in [
+            Modulus::MODULUS,
+            Modulus::MODULUS.wrapping_add(&U256::ONE),
+            U256::MAX,
+        ] {
+            assert!(bool::from(
+                Fe::from_bytes_canonical(&value.to_be_bytes()).is_none()
+            ));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes are not the expected size")]
+    fn from_bytes_canonical_wrong_length() {
+        let _ = Fe::from_bytes_canonical(&[0u8; 31]);
+    }
+
+    #[test]
+    fn montgomery_raw() {
+        let x = Fe::new(&X);
+        assert_eq!(Fe::ONE.as_montgomery(), &Modulus::R);
+        assert_eq!(Fe::from_montgomery_raw(*x.as_montgomery()), x);
+
+        // `R^2` in Montgomery form represents `R^2 * R^-1 = R`
+        assert_eq!(Fe::from_montgomery_raw(Modulus::R2).retrieve(), Modulus::R);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Montgomery form must be reduced")]
+    fn montgomery_raw_unreduced() {
+        let _ = Fe::from_montgomery_raw(Modulus::MODULUS);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "high half must be less than the modulus")]
+    fn from_wide_unreduced() {
+        let _ = Fe::from_wide((U256::ZERO, Modulus::MODULUS));
+    }
+
+    #[test]
+    fn retrieve_array() {
+        let values = [Fe::ZERO, Fe::ONE, Fe::new(&X), -Fe::ONE];
+        let expected = values.map(|x| x.retrieve());
+        assert_eq!(Fe::retrieve_array(&values), expected);
+        assert_eq!(expected[2], X);
+
+        const VALUES: [Fe; 2] = [Fe::ONE, Fe::ZERO];
+        const RETRIEVED: [U256; 2] = Fe::retrieve_array(&VALUES);
+        assert_eq!(RETRIEVED, [U256::ONE, U256::ZERO]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn retrieve_batch() {
+        let values = [Fe::ZERO, Fe::ONE, Fe::new(&X), -Fe::ONE];
+        assert_eq!(
+            Fe::retrieve_batch(&values),
+            Fe::retrieve_array(&values).to_vec()
+        );
+        assert!(Fe::retrieve_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn eq_regardless_of_construction() {
+        let x = Fe::new(&X);
+        let two = Fe::new(&U256::from_u8(2));
+
+        let (x_inv, is_some) = x.invert();
+        assert!(is_some.is_true_vartime());
+        let (x_inv_inv, _) = x_inv.invert();
+
+        let equivalents = [
+            Fe::from_wide((X, U256::ZERO)),
+            Fe::from_wide(X.mul_wide(&U256::ONE)),
+            Fe::from_bytes_canonical(&X.to_be_bytes()).unwrap(),
+            x * Fe::ONE,
+            (x + Fe::ONE) - Fe::ONE,
+            x - Fe::ZERO,
+            -(-x),
+            x.pow(&U256::ONE),
+            x_inv_inv,
+            (x * two) * two.invert().0,
+            Fe::ZERO.mul_add(&Fe::ZERO, &x),
+        ];
+
+        for y in equivalents {
+            assert!(y.montgomery_form < Modulus::MODULUS);
+            assert!(bool::from(y.ct_eq(&x)));
+            assert_eq!(y, x);
+        }
+
+        // Unreduced inputs map to the same representation as their reduced counterparts
+        assert_eq!(
+            Fe::new(&U256::MAX),
+            Fe::new(&U256::MAX.wrapping_sub(&Modulus::MODULUS))
+        );
+
+        // Zero has a unique representation as well
+        assert_eq!(Fe::new(&Modulus::MODULUS), Fe::ZERO);
+        assert_eq!(x - x, Fe::ZERO);
+        assert_eq!(-Fe::ZERO, Fe::ZERO);
+        assert!(!bool::from((x + Fe::ONE).ct_eq(&x)));
+    }
+
+    /// Evaluates the polynomial with the given coefficients (lowest degree first) at `x`,
+    /// written generically over the field.
+    fn horner<F: Field>(coefficients: &[F], x: F) -> F {
+        coefficients
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, &c| acc * x + c)
+    }
+
+    /// Computes `x^-2` using only the [`Field`] API.
+    fn inv_square<F: Field>(x: F) -> F {
+        let inv = Field::invert(&x).unwrap_or(F::ZERO);
+        Field::square(&inv)
+    }
+
+    #[test]
+    fn field_trait() {
+        let x = Fe::new(&X);
+        let two = Fe::new(&U256::from_u8(2));
+
+        // 1 + 2x + x^2 = (x + 1)^2
+        let y = horner(&[Fe::ONE, two, Fe::ONE], x);
+        assert_eq!(y, (x + Fe::ONE).square());
+        assert_eq!(Field::double(&x), two * x);
+        assert_eq!(Field::pow(&x, &U256::from_u8(3)), x * x * x);
+
+        assert_eq!(inv_square(x) * x.square(), <Fe as Field>::ONE);
+        assert_eq!(inv_square(Fe::ZERO), Fe::ZERO);
+        assert!(bool::from(Field::invert(&Fe::ZERO).is_none()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_canonical() {
+        let x = Fe::new(&X);
+
+        let serialized = serde_json::to_string(&x).unwrap();
+        assert_eq!(serialized, serde_json::to_string(&X).unwrap());
+
+        let deserialized: Fe = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, x);
+        assert_eq!(deserialized.retrieve(), X);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_bincode_canonical() {
+        let x = Fe::new(&X);
+
+        let serialized = bincode::serialize(&x).unwrap();
+        assert_eq!(serialized, X.to_le_bytes());
+
+        let deserialized: Fe = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, x);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_migrate_montgomery_form() {
+        // The format used by v0.5
+        let x = Fe::new(&X);
+        let serialized = serde_json::to_string(x.as_montgomery()).unwrap();
+
+        let montgomery_form: U256 = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(Fe::from_montgomery_raw(montgomery_form), x);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_unreduced() {
+        let serialized = serde_json::to_string(&Modulus::MODULUS).unwrap();
+        assert!(serde_json::from_str::<Fe>(&serialized).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_is_uniform_and_reduced() {
+        use crate::{Random, U64};
+        use rand_core::SeedableRng;
+
+        impl_modulus!(Small, U64, "0000000000000007");
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let mut counts = [0usize; 7];
+        for _ in 0..700 {
+            let x = Residue::<Small, { U64::LIMBS }>::random(&mut rng);
+            assert!(x.as_montgomery() < &Small::MODULUS);
+            counts[x.retrieve().as_words()[0] as usize] += 1;
+        }
+        assert!(counts.iter().all(|&count| count > 50), "{:?}", counts);
+
+        for _ in 0..100 {
+            let x = Fe::random(&mut rng);
+            assert!(x.as_montgomery() < &Modulus::MODULUS);
+            assert!(x.retrieve() < Modulus::MODULUS);
+        }
+    }
+
+    #[test]
+    fn cast_to() {
+        impl_modulus!(
+            Order,
+            U256,
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+        );
+
+        let x = Fe::new(&X);
+        let y = x.cast_to::<Order>();
+        assert_eq!(y.retrieve(), X);
+        assert_eq!(x.checked_cast_to::<Order>().unwrap(), y);
+        assert_eq!(y.cast_to::<Modulus>(), x);
+
+        // `p - 1` is not a canonical scalar, so it is reduced to `p - 1 - n`
+        let minus_one = -Fe::ONE;
+        assert_eq!(
+            minus_one.cast_to::<Order>().retrieve(),
+            Modulus::MODULUS
+                .wrapping_sub(&U256::ONE)
+                .wrapping_sub(&Order::MODULUS)
+        );
+        assert!(bool::from(minus_one.checked_cast_to::<Order>().is_none()));
     }
 }
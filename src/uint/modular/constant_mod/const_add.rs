@@ -1,6 +1,6 @@
 use core::ops::{Add, AddAssign};
 
-use crate::modular::add::add_montgomery_form;
+use crate::modular::add::{add_montgomery_form, shl_montgomery_form};
 
 use super::{Residue, ResidueParams};
 
@@ -16,6 +16,25 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: core::marker::PhantomData,
         }
     }
+
+    /// Computes `self + self`, i.e. doubles `self`.
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Computes `self * 2^k`.
+    ///
+    /// Since multiplying by a power of two commutes with the Montgomery
+    /// representation, this is computed using `k` modular doublings and
+    /// avoids a general modular multiplication.
+    ///
+    /// NOTE: this operation is variable time with respect to `k` *ONLY*.
+    pub const fn shl_mod(&self, k: usize) -> Self {
+        Self {
+            montgomery_form: shl_montgomery_form(&self.montgomery_form, k, &MOD::MODULUS),
+            phantom: core::marker::PhantomData,
+        }
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Add<&Residue<MOD, LIMBS>>
@@ -70,7 +89,11 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> AddAssign<Self> for Residue<
 
 #[cfg(test)]
 mod tests {
-    use crate::{const_residue, impl_modulus, modular::constant_mod::ResidueParams, U256};
+    use crate::{
+        const_residue, impl_modulus,
+        modular::constant_mod::{Residue, ResidueParams},
+        U256,
+    };
 
     impl_modulus!(
         Modulus,
@@ -95,4 +118,40 @@ mod tests {
 
         assert_eq!(expected, x_mod.retrieve());
     }
+
+    #[test]
+    fn shl_mod() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let x_mod = const_residue!(x, Modulus);
+
+        for k in [0, 1, 2, 63, 64, 65, 128, 255] {
+            let pow2 = U256::ONE.shl_vartime(k);
+            let pow2_mod = const_residue!(pow2, Modulus);
+
+            assert_eq!(x_mod.shl_mod(k), x_mod.mul(&pow2_mod));
+        }
+    }
+
+    #[test]
+    fn double() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let x_mod = const_residue!(x, Modulus);
+
+        assert_eq!(x_mod.double(), x_mod + x_mod);
+        assert_eq!(x_mod.double(), x_mod.shl_mod(1));
+
+        let minus_one = -Residue::<Modulus, { Modulus::LIMBS }>::ONE;
+        assert_eq!(
+            minus_one.double().retrieve(),
+            Modulus::MODULUS.wrapping_sub(&U256::from(2u8))
+        );
+    }
+
+    #[test]
+    fn shl_mod_zero() {
+        let zero = Residue::<Modulus, { Modulus::LIMBS }>::ZERO;
+        assert_eq!(zero.shl_mod(100), zero);
+    }
 }
@@ -26,6 +26,24 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
 
         (value, is_some)
     }
+
+    /// Computes the multiplicative inverse of `self` as `self^(p - 2)` using Fermat's
+    /// little theorem, where `p = MOD::MODULUS` must be prime.
+    ///
+    /// This is constant-time by construction, since it is a single call to
+    /// [`Residue::pow`]. Zero is mapped to zero.
+    pub const fn invert_fermat(&self) -> Self {
+        self.pow_minus_two()
+    }
+
+    /// Computes `self^(p - 2)` where `p = MOD::MODULUS`, using the exponent
+    /// precomputed in [`ResidueParams::MODULUS_MINUS_TWO`].
+    ///
+    /// When `p` is prime this is the multiplicative inverse of `self`, with zero mapped
+    /// to zero, which makes it the usual constant-time inversion on prime fields.
+    pub const fn pow_minus_two(&self) -> Self {
+        self.pow(&MOD::MODULUS_MINUS_TWO)
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Invert for Residue<MOD, LIMBS> {
@@ -56,6 +74,7 @@ mod tests {
     );
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn test_self_inverse() {
         let x =
             U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
@@ -66,4 +85,66 @@ mod tests {
 
         assert_eq!(res.retrieve(), U256::ONE);
     }
+
+    #[test]
+    fn test_invert_fermat() {
+        // NIST P-256 base field prime
+        impl_modulus!(
+            P256,
+            U256,
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+        );
+
+        for x in [
+            U256::ONE,
+            U256::from(2u8),
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+            P256::MODULUS.wrapping_sub(&U256::ONE),
+        ] {
+            let x_mod = const_residue!(x, P256);
+            let inv = x_mod.invert_fermat();
+
+            assert_eq!((x_mod * inv).retrieve(), U256::ONE);
+            assert_eq!(inv, x_mod.invert().0);
+        }
+
+        let zero = U256::ZERO;
+        assert_eq!(
+            const_residue!(zero, P256).invert_fermat().retrieve(),
+            U256::ZERO
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_pow_minus_two() {
+        use crate::{modular::constant_mod::Residue, NonZero, RandomMod};
+        use rand_core::SeedableRng;
+
+        // NIST P-256 base field prime
+        impl_modulus!(
+            P256,
+            U256,
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+        );
+
+        assert_eq!(
+            P256::MODULUS_MINUS_TWO,
+            U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffd")
+        );
+
+        let modulus = NonZero::new(P256::MODULUS).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        for _ in 0..32 {
+            let x = U256::random_mod(&mut rng, &modulus);
+            if x == U256::ZERO {
+                continue;
+            }
+            let x_mod = Residue::<P256, { U256::LIMBS }>::new(&x);
+            let inv = x_mod.pow_minus_two();
+
+            assert_eq!(x_mod * inv, Residue::ONE);
+            assert_eq!(inv, x_mod.invert().0);
+        }
+    }
 }
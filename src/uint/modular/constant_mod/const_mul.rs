@@ -4,8 +4,12 @@ use core::{
 };
 
 use crate::{
-    modular::mul::{mul_montgomery_form, square_montgomery_form},
+    modular::mul::{
+        mul_add_montgomery_form, mul_limb_montgomery_form, mul_montgomery_form,
+        square_montgomery_form,
+    },
     traits::Square,
+    Limb,
 };
 
 use super::{Residue, ResidueParams};
@@ -24,6 +28,23 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
         }
     }
 
+    /// Computes `self * a + b`.
+    ///
+    /// This performs a single Montgomery reduction, adding `b` to the product before
+    /// the reduction rather than after it, e.g. for Horner's rule polynomial evaluation.
+    pub const fn mul_add(&self, a: &Self, b: &Self) -> Self {
+        Self {
+            montgomery_form: mul_add_montgomery_form(
+                &self.montgomery_form,
+                &a.montgomery_form,
+                &b.montgomery_form,
+                &MOD::MODULUS,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: PhantomData,
+        }
+    }
+
     /// Computes the (reduced) square of a residue.
     pub const fn square(&self) -> Self {
         Self {
@@ -35,6 +56,45 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: PhantomData,
         }
     }
+
+    /// Multiplies by a single limb `rhs`.
+    ///
+    /// This is cheaper than converting `rhs` to a residue and calling [`Residue::mul`],
+    /// since the product is only one limb wider than the modulus, and is reduced using
+    /// the precomputed [`ResidueParams::MODULUS_NORMALIZED`] and
+    /// [`ResidueParams::MOD_LEADING_RECIPROCAL`].
+    pub const fn mul_small(&self, rhs: Limb) -> Self {
+        Self {
+            montgomery_form: mul_limb_montgomery_form(
+                &self.montgomery_form,
+                rhs,
+                &MOD::MODULUS_NORMALIZED,
+                MOD::MOD_LEADING_ZEROS,
+                &MOD::MOD_LEADING_RECIPROCAL,
+            ),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Multiplies by the constant `C` using a double-and-add chain over its bits.
+    ///
+    /// This only needs modular additions, which makes it cheaper than a general
+    /// multiplication for small constants such as curve cofactors. The chain only
+    /// depends on `C`, so this is constant time with respect to `self`.
+    pub const fn mul_const<const C: u64>(&self) -> Self {
+        let mut result = Self::ZERO;
+
+        let mut i = u64::BITS - C.leading_zeros();
+        while i > 0 {
+            i -= 1;
+            result = result.add(&result);
+            if (C >> i) & 1 == 1 {
+                result = result.add(self);
+            }
+        }
+
+        result
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Mul<&Residue<MOD, LIMBS>>
@@ -92,3 +152,130 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Square for Residue<MOD, LIMB
         Residue::square(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        const_residue, impl_modulus,
+        modular::constant_mod::{Residue, ResidueParams},
+        Limb, U256,
+    };
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+    );
+
+    impl_modulus!(
+        SmallModulus,
+        U256,
+        "0000000000000000000000000000000000000000000000000000000000000065"
+    );
+
+    #[test]
+    fn mul_add() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let y =
+            U256::from_be_hex("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251");
+        let x_mod = const_residue!(x, Modulus);
+        let y_mod = const_residue!(y, Modulus);
+        let minus_one = -Residue::<Modulus, { U256::LIMBS }>::ONE;
+
+        for (a, b) in [
+            (x_mod, y_mod),
+            (y_mod, x_mod),
+            (minus_one, minus_one),
+            (Residue::ZERO, minus_one),
+            (minus_one, Residue::ZERO),
+        ] {
+            assert_eq!(x_mod.mul_add(&a, &b), x_mod * a + b);
+            assert_eq!(minus_one.mul_add(&a, &b), minus_one * a + b);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn mul_add_random() {
+        use crate::Random;
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        for _ in 0..100 {
+            let x = Residue::<Modulus, { U256::LIMBS }>::random(&mut rng);
+            let a = Residue::random(&mut rng);
+            let b = Residue::random(&mut rng);
+            assert_eq!(x.mul_add(&a, &b), x * a + b);
+        }
+
+        // Horner's rule evaluation of `c0 + c1 * t + c2 * t^2`
+        let t = Residue::<Modulus, { U256::LIMBS }>::random(&mut rng);
+        let c = [
+            Residue::random(&mut rng),
+            Residue::random(&mut rng),
+            Residue::random(&mut rng),
+        ];
+        let horner = c[2].mul_add(&t, &c[1]).mul_add(&t, &c[0]);
+        assert_eq!(horner, c[0] + c[1] * t + c[2] * t.square());
+    }
+
+    #[test]
+    fn mul_small() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let x_mod = const_residue!(x, Modulus);
+
+        for rhs in [Limb::ZERO, Limb::ONE, Limb(2), Limb(0x1234_5678), Limb::MAX] {
+            let rhs_uint = U256::from_word(rhs.0);
+            let expected = x_mod.mul(&const_residue!(rhs_uint, Modulus));
+            assert_eq!(x_mod.mul_small(rhs), expected);
+        }
+    }
+
+    #[test]
+    fn mul_small_short_modulus() {
+        let x = U256::from(100u8);
+        let x_mod = const_residue!(x, SmallModulus);
+
+        for rhs in [Limb::ZERO, Limb(7), Limb(101), Limb::MAX] {
+            let rhs_uint = U256::from_word(rhs.0);
+            let expected = x_mod.mul(&const_residue!(rhs_uint, SmallModulus));
+            assert_eq!(x_mod.mul_small(rhs), expected);
+        }
+    }
+
+    #[test]
+    fn mul_const() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let x_mod = const_residue!(x, Modulus);
+
+        fn check<const C: u64>(x_mod: Residue<Modulus, { U256::LIMBS }>) {
+            let c = U256::from_u64(C);
+            assert_eq!(
+                x_mod.mul_const::<C>(),
+                x_mod.mul(&const_residue!(c, Modulus))
+            );
+        }
+
+        check::<0>(x_mod);
+        check::<1>(x_mod);
+        check::<2>(x_mod);
+        check::<3>(x_mod);
+        check::<4>(x_mod);
+        check::<8>(x_mod);
+        check::<0x1234>(x_mod);
+        check::<{ u64::MAX }>(x_mod);
+    }
+
+    #[test]
+    fn mul_const_short_modulus() {
+        let x = U256::from(100u8);
+        let x_mod = const_residue!(x, SmallModulus);
+
+        // 100 * 8 = 800 = 7 * 101 + 93
+        assert_eq!(x_mod.mul_const::<8>().retrieve(), U256::from(93u8));
+        assert_eq!(x_mod.mul_const::<101>().retrieve(), U256::ZERO);
+    }
+}
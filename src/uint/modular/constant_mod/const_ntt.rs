@@ -0,0 +1,82 @@
+use super::{Residue, ResidueParams};
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
+    /// Reorders `values` in place by bit-reversed index, as required by an in-place
+    /// radix-2 number-theoretic transform: the element at index `i` is swapped with the one
+    /// at the index whose `log2(values.len())` low bits are those of `i` in reverse order.
+    ///
+    /// Applying this permutation twice leaves `values` unchanged.
+    ///
+    /// Panics if the length of `values` is not a power of two. Empty slices are left as is.
+    pub fn ntt_bit_reverse(values: &mut [Self]) {
+        let len = values.len();
+        if len <= 1 {
+            return;
+        }
+        assert!(len.is_power_of_two(), "length must be a power of two");
+
+        let bits = len.trailing_zeros();
+
+        for i in 0..len {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{impl_modulus, modular::constant_mod::Residue, U64};
+
+    // 2^64 - 2^32 + 1, a prime with a large power-of-two subgroup
+    impl_modulus!(Modulus, U64, "ffffffff00000001");
+
+    fn residues<const N: usize>() -> [Residue<Modulus, { U64::LIMBS }>; N] {
+        let mut i = 0;
+        [(); N].map(|_| {
+            i += 1;
+            Residue::new(&U64::from_u64(i))
+        })
+    }
+
+    #[test]
+    fn test_ntt_bit_reverse() {
+        let mut values = residues::<8>();
+        let original = values;
+        Residue::ntt_bit_reverse(&mut values);
+
+        let permutation = [0, 4, 2, 6, 1, 5, 3, 7];
+        for (value, index) in values.iter().zip(permutation) {
+            assert_eq!(*value, original[index]);
+        }
+    }
+
+    #[test]
+    fn test_ntt_bit_reverse_involution() {
+        let mut values = residues::<64>();
+        let original = values;
+
+        Residue::ntt_bit_reverse(&mut values);
+        assert_ne!(values, original);
+        Residue::ntt_bit_reverse(&mut values);
+        assert_eq!(values, original);
+
+        // A single element is its own permutation
+        let mut single = residues::<1>();
+        Residue::ntt_bit_reverse(&mut single);
+        assert_eq!(single, residues::<1>());
+
+        // So is an empty slice
+        let mut empty = residues::<0>();
+        Residue::ntt_bit_reverse(&mut empty);
+    }
+
+    #[test]
+    #[should_panic(expected = "length must be a power of two")]
+    fn test_ntt_bit_reverse_invalid_length() {
+        let mut values = residues::<6>();
+        Residue::ntt_bit_reverse(&mut values);
+    }
+}
@@ -1,9 +1,41 @@
-use crate::{modular::pow::pow_montgomery_form, PowBoundedExp, Uint};
+use crate::{
+    modular::pow::{
+        pow_montgomery_form, pow_montgomery_form_exponent, pow_montgomery_form_vartime,
+    },
+    Exponent, PowBoundedExp, Uint,
+};
+use core::marker::PhantomData;
+use subtle::Choice;
+
+#[cfg(feature = "rand_core")]
+use {
+    crate::{
+        modular::pow::{blind_exponent, pow_montgomery_form_wide},
+        Limb, Random,
+    },
+    rand_core::CryptoRngCore,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        modular::pow::{compute_powers, pow_montgomery_form_with_powers},
+        BoxedUint, Pow,
+    },
+    alloc::vec::Vec,
+};
 
 use super::{Residue, ResidueParams};
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
     /// Raises to the `exponent` power.
+    ///
+    /// This is constant time with respect to `exponent`: every window performs the same
+    /// operations, and the table of precomputed powers is scanned in full on each lookup,
+    /// so there are no memory accesses indexed by the exponent.
+    ///
+    /// Any base raised to the zero exponent is one, including `0^0`, and zero raised
+    /// to any nonzero exponent is zero.
     pub const fn pow(&self, exponent: &Uint<LIMBS>) -> Residue<MOD, LIMBS> {
         self.pow_bounded_exp(exponent, Uint::<LIMBS>::BITS)
     }
@@ -27,9 +59,116 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
                 &MOD::R,
                 MOD::MOD_NEG_INV,
             ),
-            phantom: core::marker::PhantomData,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Raises to the `exponent` power, for any exponent type implementing [`Exponent`],
+    /// such as a [`Uint`] of a different width or a
+    /// [`BoxedUint`][crate::BoxedUint].
+    ///
+    /// This uses the same constant-time method as [`Residue::pow`], taking all
+    /// `exponent.bits_precision()` bits into account.
+    pub fn pow_generic<E: Exponent + ?Sized>(&self, exponent: &E) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_exponent(
+                &self.montgomery_form,
+                exponent,
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Raises to the `exponent` power using a sliding window.
+    ///
+    /// This is faster than [`Residue::pow`] for sparse exponents such as `65537`:
+    /// only the `exponent.bits_vartime()` significant bits are processed, and
+    /// runs of zero bits only cost a squaring each.
+    ///
+    /// NOTE: this operation is variable time with respect to `exponent`,
+    /// so it must only be used with public exponents.
+    pub const fn pow_vartime(&self, exponent: &Uint<LIMBS>) -> Residue<MOD, LIMBS> {
+        Self {
+            montgomery_form: pow_montgomery_form_vartime(
+                &self.montgomery_form,
+                exponent,
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Raises to the `exponent` power, blinding the exponent with a random multiple
+    /// of `group_order` as a side-channel countermeasure.
+    ///
+    /// `group_order` must be a multiple of the multiplicative order of `self`
+    /// (e.g. `p - 1` for a prime modulus `p`, or `λ(n)` for an RSA modulus `n`),
+    /// otherwise the result is not equal to the one of [`Residue::pow`].
+    #[cfg(feature = "rand_core")]
+    pub fn pow_blinded(
+        &self,
+        exponent: &Uint<LIMBS>,
+        group_order: &Uint<LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Self {
+        let (exponent, exponent_hi) = blind_exponent(exponent, group_order, Limb::random(rng));
+        Self {
+            montgomery_form: pow_montgomery_form_wide(
+                &self.montgomery_form,
+                &exponent,
+                exponent_hi,
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: PhantomData,
         }
     }
+
+    /// Raises to each of the `exponents` powers.
+    ///
+    /// The table of powers of `self` is computed once and shared between all the exponents,
+    /// which is faster than calling [`Residue::pow`] for each of them.
+    #[cfg(feature = "alloc")]
+    pub fn pow_many(&self, exponents: &[Uint<LIMBS>]) -> Vec<Self> {
+        let powers = compute_powers(
+            &self.montgomery_form,
+            &MOD::MODULUS,
+            &MOD::R,
+            MOD::MOD_NEG_INV,
+        );
+        exponents
+            .iter()
+            .map(|exponent| Self {
+                montgomery_form: pow_montgomery_form_with_powers(
+                    &powers,
+                    exponent,
+                    Uint::<LIMBS>::BITS,
+                    &MOD::MODULUS,
+                    &MOD::R,
+                    MOD::MOD_NEG_INV,
+                ),
+                phantom: PhantomData,
+            })
+            .collect()
+    }
+
+    /// Returns a truthy [`Choice`] if `self` is a square modulo `MOD::MODULUS`, which must be an odd prime.
+    ///
+    /// Uses Euler's criterion `self^((p - 1) / 2) == 1`, which is cheaper than computing
+    /// a square root when only its existence matters. Zero is considered a square.
+    pub fn is_square(&self) -> Choice {
+        // `p` is odd, so `(p - 1) / 2 == p >> 1`
+        let exponent = MOD::MODULUS.shr_vartime(1);
+        let is_one = Uint::ct_eq(&self.pow(&exponent).montgomery_form, &MOD::R);
+        let is_zero = self.montgomery_form.ct_is_nonzero().not();
+        is_one.or(is_zero).into()
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> PowBoundedExp<Uint<LIMBS>>
@@ -40,9 +179,23 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> PowBoundedExp<Uint<LIMBS>>
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Pow<BoxedUint> for Residue<MOD, LIMBS> {
+    fn pow(&self, exponent: &BoxedUint) -> Self {
+        self.pow_generic(exponent)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{const_residue, impl_modulus, modular::constant_mod::ResidueParams, U256};
+    use crate::{
+        const_residue, impl_modulus,
+        modular::{
+            constant_mod::{Residue, ResidueParams},
+            runtime_mod::{DynResidue, DynResidueParams},
+        },
+        U256, U512, U64,
+    };
 
     impl_modulus!(
         Modulus,
@@ -95,4 +248,216 @@ mod tests {
             U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
         assert_eq!(res.retrieve(), expected);
     }
+
+    #[test]
+    fn test_pow_generic() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+        let base_dyn = DynResidue::new(&base, DynResidueParams::new(&Modulus::MODULUS));
+
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        let expected =
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
+
+        assert_eq!(base_mod.pow_generic(&exponent).retrieve(), expected);
+        assert_eq!(base_dyn.pow_generic(&exponent).retrieve(), expected);
+
+        // Exponents of other widths
+        let wide: U512 = exponent.resize();
+        assert_eq!(base_mod.pow_generic(&wide).retrieve(), expected);
+        assert_eq!(base_dyn.pow_generic(&wide).retrieve(), expected);
+
+        let narrow = U64::from_u64(0xdead_beef_cafe_f00d);
+        assert_eq!(
+            base_mod.pow_generic(&narrow),
+            base_mod.pow(&narrow.resize())
+        );
+        assert_eq!(
+            base_dyn.pow_generic(&narrow),
+            base_dyn.pow(&narrow.resize())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_pow_boxed_exponent() {
+        use crate::{BoxedUint, Pow};
+
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+        let base_dyn = DynResidue::new(&base, DynResidueParams::new(&Modulus::MODULUS));
+
+        let exponent = BoxedUint::from(U256::from_be_hex(
+            "77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685",
+        ));
+        let expected =
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421");
+
+        assert_eq!(Pow::pow(&base_mod, &exponent).retrieve(), expected);
+        assert_eq!(Pow::pow(&base_dyn, &exponent).retrieve(), expected);
+
+        // The width of a boxed exponent is chosen at runtime
+        let exponent = exponent.resize(2 * U256::LIMBS);
+        assert_eq!(Pow::pow(&base_dyn, &exponent).retrieve(), expected);
+    }
+
+    #[test]
+    fn test_powmod_edge_cases() {
+        let zero = Residue::<Modulus, { U256::LIMBS }>::ZERO;
+        let one = Residue::<Modulus, { U256::LIMBS }>::ONE;
+        let x =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let x_mod = const_residue!(x, Modulus);
+        let params = DynResidueParams::new(&Modulus::MODULUS);
+
+        // `0^0 = 1` and `x^0 = 1`
+        for base in [zero, one, x_mod] {
+            assert_eq!(base.pow(&U256::ZERO), one);
+            assert_eq!(base.pow_vartime(&U256::ZERO), one);
+            assert_eq!(base.pow_bounded_exp(&U256::MAX, 0), one);
+
+            let dyn_base = DynResidue::new(&base.retrieve(), params);
+            assert_eq!(dyn_base.pow(&U256::ZERO).retrieve(), U256::ONE);
+            assert_eq!(dyn_base.pow_vartime(&U256::ZERO).retrieve(), U256::ONE);
+        }
+
+        // `0^k = 0` and `1^k = 1` for `k > 0`
+        for exponent in [U256::ONE, U256::from(2u8), U256::from(105u64), U256::MAX] {
+            assert_eq!(zero.pow(&exponent), zero);
+            assert_eq!(zero.pow_vartime(&exponent), zero);
+            assert_eq!(one.pow(&exponent), one);
+            assert_eq!(
+                DynResidue::new(&U256::ZERO, params)
+                    .pow(&exponent)
+                    .retrieve(),
+                U256::ZERO
+            );
+        }
+
+        // `x^1 = x`
+        assert_eq!(x_mod.pow(&U256::ONE), x_mod);
+        assert_eq!(x_mod.pow_bounded_exp(&U256::ONE, 1), x_mod);
+    }
+
+    #[test]
+    fn test_powmod_vartime() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+
+        let exponents = [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(105u64),
+            U256::from(65537u64),
+            U256::MAX,
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+        ];
+
+        for exponent in &exponents {
+            assert_eq!(base_mod.pow_vartime(exponent), base_mod.pow(exponent));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_pow_many() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+
+        let exponents = [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(105u64),
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+        ];
+
+        let results = base_mod.pow_many(&exponents);
+        assert_eq!(results.len(), exponents.len());
+        for (result, exponent) in results.iter().zip(exponents.iter()) {
+            assert_eq!(*result, base_mod.pow(exponent));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_pow_blinded() {
+        use rand_core::SeedableRng;
+
+        // NIST P-256 base field prime
+        impl_modulus!(
+            P256,
+            U256,
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+        );
+        let group_order = P256::MODULUS.wrapping_sub(&U256::ONE);
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, P256);
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let expected = base_mod.pow(&exponent);
+        for _ in 0..4 {
+            assert_eq!(
+                base_mod.pow_blinded(&exponent, &group_order, &mut rng),
+                expected
+            );
+        }
+        assert_eq!(
+            base_mod.pow_blinded(&U256::ZERO, &group_order, &mut rng),
+            base_mod.pow(&U256::ZERO)
+        );
+    }
+
+    fn check_is_square<MOD: ResidueParams<{ U64::LIMBS }>>(p: u64) {
+        let params = DynResidueParams::new(&MOD::MODULUS);
+
+        for x in 0..p {
+            let expected = (0..p).any(|y| y * y % p == x);
+            let residue = Residue::<MOD, { U64::LIMBS }>::new(&U64::from(x));
+            assert_eq!(bool::from(residue.is_square()), expected, "{} mod {}", x, p);
+            let residue = DynResidue::new(&U64::from(x), params);
+            assert_eq!(bool::from(residue.is_square()), expected, "{} mod {}", x, p);
+        }
+    }
+
+    #[test]
+    fn test_is_square_small_primes() {
+        impl_modulus!(P23, U64, "0000000000000017");
+        impl_modulus!(P97, U64, "0000000000000061");
+        impl_modulus!(P257, U64, "0000000000000101");
+
+        check_is_square::<P23>(23);
+        check_is_square::<P97>(97);
+        check_is_square::<P257>(257);
+    }
+
+    #[test]
+    fn test_is_square_p256() {
+        // NIST P-256 base field prime, which is 3 mod 4, so -1 is not a square
+        impl_modulus!(
+            P256,
+            U256,
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+        );
+
+        let x =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let x_mod = const_residue!(x, P256);
+        let minus_one = -Residue::<P256, { U256::LIMBS }>::ONE;
+
+        assert!(bool::from(x_mod.square().is_square()));
+        assert!(bool::from(
+            Residue::<P256, { U256::LIMBS }>::ZERO.is_square()
+        ));
+        assert!(!bool::from(minus_one.is_square()));
+        assert!(!bool::from((minus_one * x_mod.square()).is_square()));
+    }
 }
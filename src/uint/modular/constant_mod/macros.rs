@@ -40,6 +40,22 @@ macro_rules! impl_modulus {
     };
 }
 
+#[macro_export]
+/// Defines a modulus with the given name and big endian hex value, inferring the width of the
+/// underlying `Uint` from the length of the hex string. The hex string must be a whole number of
+/// limbs long (i.e. a multiple of 16 characters on 64-bit platforms and 8 on 32-bit platforms).
+/// For example, `define_residue!(Fp, modulus = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");`
+/// defines a 256-bit modulus named `Fp`, which can then be used as `Residue<Fp, { Fp::LIMBS }>`.
+macro_rules! define_residue {
+    ($name:ident, modulus = $value:expr) => {
+        $crate::impl_modulus!(
+            $name,
+            $crate::Uint<{ $crate::nlimbs!($value.len() * 4) }>,
+            $value
+        );
+    };
+}
+
 #[macro_export]
 /// Creates a `Residue` with the given value for a specific modulus.
 /// For example, `residue!(U256::from(105u64), MyModulus);` creates a `Residue` for 105 mod `MyModulus`.
@@ -1,4 +1,7 @@
-use crate::{Limb, Uint};
+use crate::{
+    uint::div_limb::{div_rem_limb_wider_with_reciprocal, Reciprocal},
+    Limb, Uint,
+};
 
 use super::reduction::montgomery_reduction;
 
@@ -12,6 +15,23 @@ pub(crate) const fn mul_montgomery_form<const LIMBS: usize>(
     montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv)
 }
 
+/// Computes `a * b + c` with a single Montgomery reduction.
+///
+/// Since `a` and `b` are reduced, the high half of `a * b` is less than `modulus`, so `c * R`
+/// can be added to the product by a modular addition to the high half, which keeps the
+/// input of the reduction below `modulus * R`.
+pub(crate) const fn mul_add_montgomery_form<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    b: &Uint<LIMBS>,
+    c: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let (lo, hi) = a.mul_wide(b);
+    let hi = hi.add_mod(c, modulus);
+    montgomery_reduction::<LIMBS>(&(lo, hi), modulus, mod_neg_inv)
+}
+
 pub(crate) const fn square_montgomery_form<const LIMBS: usize>(
     a: &Uint<LIMBS>,
     modulus: &Uint<LIMBS>,
@@ -20,3 +40,47 @@ pub(crate) const fn square_montgomery_form<const LIMBS: usize>(
     let product = a.square_wide();
     montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv)
 }
+
+/// Computes the reciprocal of the top limb of `normalized_modulus`, i.e. the modulus shifted left
+/// by its leading zeros so that its top bit is set, as needed by [`mul_limb_montgomery_form`].
+pub(crate) const fn modulus_leading_reciprocal<const LIMBS: usize>(
+    normalized_modulus: &Uint<LIMBS>,
+) -> Reciprocal {
+    Reciprocal::ct_new(normalized_modulus.limbs[LIMBS - 1]).0
+}
+
+/// Multiplies `a` (assumed to be reduced) by a single limb `b` modulo the modulus, given the
+/// modulus shifted left by its `leading_zeros` and the reciprocal precomputed from it with
+/// [`modulus_leading_reciprocal`].
+///
+/// Since `a * b` is at most one limb wider than the modulus, it is reduced with
+/// [`div_rem_limb_wider_with_reciprocal`].
+/// `R * a * b = (R * a) * b`, so this works on Montgomery form values as is.
+///
+/// NOTE: the bit length of `modulus` is leaked in the time pattern.
+pub(crate) const fn mul_limb_montgomery_form<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    b: Limb,
+    normalized_modulus: &Uint<LIMBS>,
+    leading_zeros: usize,
+    leading_reciprocal: &Reciprocal,
+) -> Uint<LIMBS> {
+    let mut lo = Uint::ZERO;
+    let mut carry = Limb::ZERO;
+    let mut i = 0;
+    while i < LIMBS {
+        let (n, c) = Limb::ZERO.mac(a.limbs[i], b, carry);
+        lo.limbs[i] = n;
+        carry = c;
+        i += 1;
+    }
+
+    div_rem_limb_wider_with_reciprocal(
+        &lo,
+        carry,
+        normalized_modulus,
+        leading_zeros,
+        leading_reciprocal,
+    )
+    .1
+}
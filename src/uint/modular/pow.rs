@@ -1,4 +1,4 @@
-use crate::{Limb, Uint, Word};
+use crate::{Exponent, Limb, Uint, Word};
 
 use super::mul::{mul_montgomery_form, square_montgomery_form};
 
@@ -18,9 +18,73 @@ pub const fn pow_montgomery_form<const LIMBS: usize>(
         return *r; // 1 in Montgomery form
     }
 
-    const WINDOW: usize = 4;
-    const WINDOW_MASK: Word = (1 << WINDOW) - 1;
+    let powers = compute_powers(x, modulus, r, mod_neg_inv);
+    pow_montgomery_form_with_powers(&powers, exponent, exponent_bits, modulus, r, mod_neg_inv)
+}
+
+/// Performs modular exponentiation with an exponent one limb wider than the modulus,
+/// given as `exponent + exponent_hi * 2^BITS`.
+#[cfg(feature = "rand_core")]
+pub(crate) const fn pow_montgomery_form_wide<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<LIMBS>,
+    exponent_hi: Limb,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let lo = pow_montgomery_form(x, exponent, Uint::<LIMBS>::BITS, modulus, r, mod_neg_inv);
+
+    // x^(2^BITS)
+    let mut x_hi = *x;
+    let mut i = 0;
+    while i < Uint::<LIMBS>::BITS {
+        x_hi = square_montgomery_form(&x_hi, modulus, mod_neg_inv);
+        i += 1;
+    }
+    let hi = pow_montgomery_form(
+        &x_hi,
+        &Uint::from_word(exponent_hi.0),
+        Limb::BITS,
+        modulus,
+        r,
+        mod_neg_inv,
+    );
+
+    mul_montgomery_form(&lo, &hi, modulus, mod_neg_inv)
+}
 
+/// Computes `exponent + blinding * group_order`,
+/// returning the result as a `(lo, hi)` pair.
+#[cfg(feature = "rand_core")]
+pub(crate) const fn blind_exponent<const LIMBS: usize>(
+    exponent: &Uint<LIMBS>,
+    group_order: &Uint<LIMBS>,
+    blinding: Limb,
+) -> (Uint<LIMBS>, Limb) {
+    let mut lo = *exponent;
+    let mut carry = Limb::ZERO;
+    let mut i = 0;
+    while i < LIMBS {
+        let (n, c) = lo.limbs[i].mac(group_order.limbs[i], blinding, carry);
+        lo.limbs[i] = n;
+        carry = c;
+        i += 1;
+    }
+    (lo, carry)
+}
+
+pub(crate) const WINDOW: usize = 4;
+pub(crate) const WINDOW_MASK: Word = (1 << WINDOW) - 1;
+
+/// Precomputes the table of powers `x^i` for `i` in `0..2^WINDOW`,
+/// to be used with [`pow_montgomery_form_with_powers`].
+pub(crate) const fn compute_powers<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> [Uint<LIMBS>; 1 << WINDOW] {
     // powers[i] contains x^i
     let mut powers = [*r; 1 << WINDOW];
     powers[1] = *x;
@@ -29,6 +93,46 @@ pub const fn pow_montgomery_form<const LIMBS: usize>(
         powers[i] = mul_montgomery_form(&powers[i - 1], x, modulus, mod_neg_inv);
         i += 1;
     }
+    powers
+}
+
+/// Returns `powers[idx]` in constant time.
+///
+/// Every entry of the table is read and conditionally selected, so neither the
+/// control flow nor the memory access pattern depends on `idx`, which keeps
+/// secret exponent windows from leaking through cache timing.
+pub(crate) const fn select_power<const LIMBS: usize>(
+    powers: &[Uint<LIMBS>; 1 << WINDOW],
+    idx: Word,
+) -> Uint<LIMBS> {
+    let mut power = powers[0];
+    let mut i = 1;
+    while i < 1 << WINDOW {
+        let choice = Limb::ct_eq(Limb(i as Word), Limb(idx));
+        power = Uint::<LIMBS>::ct_select(&power, &powers[i], choice);
+        i += 1;
+    }
+    power
+}
+
+/// Performs modular exponentiation using Montgomery's ladder,
+/// with a table of powers precomputed by [`compute_powers`].
+///
+/// The table is accessed with [`select_power`], so there are no memory accesses
+/// indexed by the exponent.
+///
+/// NOTE: `exponent_bits` is leaked in the time pattern.
+pub(crate) const fn pow_montgomery_form_with_powers<const LIMBS: usize>(
+    powers: &[Uint<LIMBS>; 1 << WINDOW],
+    exponent: &Uint<LIMBS>,
+    exponent_bits: usize,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    if exponent_bits == 0 {
+        return *r; // 1 in Montgomery form
+    }
 
     let starting_limb = (exponent_bits - 1) / Limb::BITS;
     let starting_bit_in_limb = (exponent_bits - 1) % Limb::BITS;
@@ -52,28 +156,217 @@ pub const fn pow_montgomery_form<const LIMBS: usize>(
 
             let mut idx = (w >> (window_num * WINDOW)) & WINDOW_MASK;
 
-            if limb_num == starting_limb && window_num == starting_window {
+            let is_first = limb_num == starting_limb && window_num == starting_window;
+            if is_first {
                 idx &= starting_window_mask;
-            } else {
-                let mut i = 0;
-                while i < WINDOW {
-                    i += 1;
-                    z = square_montgomery_form(&z, modulus, mod_neg_inv);
-                }
             }
 
-            // Constant-time lookup in the array of powers
-            let mut power = powers[0];
-            let mut i = 1;
-            while i < 1 << WINDOW {
-                let choice = Limb::ct_eq(Limb(i as Word), Limb(idx));
-                power = Uint::<LIMBS>::ct_select(&power, &powers[i], choice);
-                i += 1;
-            }
+            z = pow_window_step(&z, powers, idx, is_first, modulus, mod_neg_inv);
+        }
+    }
+
+    z
+}
+
+/// Processes a single window of the fixed-window exponentiation, shared by
+/// [`pow_montgomery_form_with_powers`] and [`pow_montgomery_form_exponent`]: shifts the
+/// accumulator `z` by `WINDOW` bits, unless this is the first (most significant) window,
+/// and multiplies it by `x^idx`.
+const fn pow_window_step<const LIMBS: usize>(
+    z: &Uint<LIMBS>,
+    powers: &[Uint<LIMBS>; 1 << WINDOW],
+    idx: Word,
+    is_first: bool,
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let mut z = *z;
 
-            z = mul_montgomery_form(&z, &power, modulus, mod_neg_inv);
+    if !is_first {
+        let mut i = 0;
+        while i < WINDOW {
+            i += 1;
+            z = square_montgomery_form(&z, modulus, mod_neg_inv);
         }
     }
 
+    let power = select_power(powers, idx);
+    mul_montgomery_form(&z, &power, modulus, mod_neg_inv)
+}
+
+/// Performs modular exponentiation with an exponent of any type implementing [`Exponent`],
+/// using the same fixed-window method as [`pow_montgomery_form`].
+///
+/// The windows are assembled from [`Exponent::nth_bit`], and the table of powers is accessed
+/// with [`select_power`], so this is constant time with respect to the value of `exponent`.
+///
+/// NOTE: `exponent.bits_precision()` is leaked in the time pattern.
+pub(crate) fn pow_montgomery_form_exponent<const LIMBS: usize, E: Exponent + ?Sized>(
+    x: &Uint<LIMBS>,
+    exponent: &E,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let windows = (exponent.bits_precision() + WINDOW - 1) / WINDOW;
+    let powers = compute_powers(x, modulus, r, mod_neg_inv);
+
+    let mut z = *r; // 1 in Montgomery form
+
+    for window_num in (0..windows).rev() {
+        let mut idx: Word = 0;
+        for i in 0..WINDOW {
+            let bit = exponent.nth_bit(window_num * WINDOW + i);
+            idx |= (bit.unwrap_u8() as Word) << i;
+        }
+
+        let is_first = window_num + 1 == windows;
+        z = pow_window_step(&z, &powers, idx, is_first, modulus, mod_neg_inv);
+    }
+
+    z
+}
+
+/// Performs modular exponentiation using a left-to-right sliding window.
+///
+/// NOTE: this is variable-time with respect to `exponent`, and must only be used
+/// with public exponents.
+pub const fn pow_montgomery_form_vartime<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    // odd_powers[i] contains x^(2i + 1)
+    let x2 = square_montgomery_form(x, modulus, mod_neg_inv);
+    let mut odd_powers = [*x; 1 << (WINDOW - 1)];
+    let mut i = 1;
+    while i < odd_powers.len() {
+        odd_powers[i] = mul_montgomery_form(&odd_powers[i - 1], &x2, modulus, mod_neg_inv);
+        i += 1;
+    }
+
+    let mut z = *r; // 1 in Montgomery form
+
+    let mut i = exponent.bits_vartime();
+    while i > 0 {
+        if !exponent.bit_vartime(i - 1) {
+            z = square_montgomery_form(&z, modulus, mod_neg_inv);
+            i -= 1;
+            continue;
+        }
+
+        // Find the longest window `[l, i)` of at most `WINDOW` bits ending with a set bit
+        let mut l = i.saturating_sub(WINDOW);
+        while !exponent.bit_vartime(l) {
+            l += 1;
+        }
+
+        let mut idx = 0;
+        while i > l {
+            i -= 1;
+            z = square_montgomery_form(&z, modulus, mod_neg_inv);
+            idx = (idx << 1) | exponent.bit_vartime(i) as usize;
+        }
+
+        z = mul_montgomery_form(&z, &odd_powers[idx >> 1], modulus, mod_neg_inv);
+    }
+
     z
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compute_powers, pow_montgomery_form, pow_montgomery_form_exponent, select_power, WINDOW,
+    };
+    use crate::{impl_modulus, modular::constant_mod::ResidueParams, Word, U128, U256, U64};
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+    );
+
+    #[test]
+    fn select_power_matches_pow() {
+        let x = Modulus::R2; // 2^256 mod p in Montgomery form
+        let powers = compute_powers(&x, &Modulus::MODULUS, &Modulus::R, Modulus::MOD_NEG_INV);
+
+        for i in 0..1 << WINDOW {
+            let expected = pow_montgomery_form(
+                &x,
+                &U256::from_u8(i),
+                WINDOW,
+                &Modulus::MODULUS,
+                &Modulus::R,
+                Modulus::MOD_NEG_INV,
+            );
+            assert_eq!(select_power(&powers, i as Word), expected);
+            assert_eq!(select_power(&powers, i as Word), powers[i as usize]);
+        }
+    }
+
+    #[test]
+    fn pow_exponent_matches_pow() {
+        let x = Modulus::R2;
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let expected = pow_montgomery_form(
+            &x,
+            &exponent,
+            U256::BITS,
+            &Modulus::MODULUS,
+            &Modulus::R,
+            Modulus::MOD_NEG_INV,
+        );
+        let actual = pow_montgomery_form_exponent(
+            &x,
+            &exponent,
+            &Modulus::MODULUS,
+            &Modulus::R,
+            Modulus::MOD_NEG_INV,
+        );
+        assert_eq!(actual, expected);
+
+        // Exponents narrower than the modulus, whose width is not a multiple of the window
+        for exponent in [U64::ZERO, U64::ONE, U64::MAX] {
+            let expected = pow_montgomery_form(
+                &x,
+                &exponent.resize(),
+                U64::BITS,
+                &Modulus::MODULUS,
+                &Modulus::R,
+                Modulus::MOD_NEG_INV,
+            );
+            let actual = pow_montgomery_form_exponent(
+                &x,
+                &exponent,
+                &Modulus::MODULUS,
+                &Modulus::R,
+                Modulus::MOD_NEG_INV,
+            );
+            assert_eq!(actual, expected);
+        }
+
+        let exponent = U128::MAX.shr_vartime(3);
+        let expected = pow_montgomery_form(
+            &x,
+            &exponent.resize(),
+            U128::BITS - 3,
+            &Modulus::MODULUS,
+            &Modulus::R,
+            Modulus::MOD_NEG_INV,
+        );
+        let actual = pow_montgomery_form_exponent(
+            &x,
+            &exponent,
+            &Modulus::MODULUS,
+            &Modulus::R,
+            Modulus::MOD_NEG_INV,
+        );
+        assert_eq!(actual, expected);
+    }
+}
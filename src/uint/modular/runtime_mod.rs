@@ -1,9 +1,23 @@
-use crate::{Limb, Uint, Word};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
-use super::{reduction::montgomery_reduction, Retrieve};
+use crate::{Limb, Reciprocal, Uint, Word};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{
+    mul::{modulus_leading_reciprocal, mul_montgomery_form},
+    reduction::montgomery_reduction,
+    Retrieve,
+};
 
 /// Additions between residues with a modulus set at runtime
 mod runtime_add;
+/// Exponentiation using the Chinese remainder theorem with moduli set at runtime
+mod runtime_crt;
+/// Fixed-base exponentiation of residues with a modulus set at runtime
+#[cfg(feature = "alloc")]
+mod runtime_fixed_base;
 /// Multiplicative inverses of residues with a modulus set at runtime
 mod runtime_inv;
 /// Multiplications between residues with a modulus set at runtime
@@ -15,6 +29,11 @@ mod runtime_pow;
 /// Subtractions between residues with a modulus set at runtime
 mod runtime_sub;
 
+pub use runtime_crt::crt_exp;
+
+#[cfg(feature = "alloc")]
+pub use runtime_fixed_base::FixedBaseTable;
+
 /// The parameters to efficiently go to and from the Montgomery form for a modulus provided at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DynResidueParams<const LIMBS: usize> {
@@ -29,6 +48,12 @@ pub struct DynResidueParams<const LIMBS: usize> {
     // The lowest limbs of -(MODULUS^-1) mod R
     // We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
     mod_neg_inv: Limb,
+    // Number of leading zeros of the modulus, used to multiply by a single limb
+    mod_leading_zeros: usize,
+    // The modulus shifted left by `mod_leading_zeros`, so that its top bit is set
+    modulus_normalized: Uint<LIMBS>,
+    // Reciprocal of the top limb of the normalized modulus, used to multiply by a single limb
+    mod_leading_reciprocal: Reciprocal,
 }
 
 impl<const LIMBS: usize> DynResidueParams<LIMBS> {
@@ -39,6 +64,8 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
         let mod_neg_inv =
             Limb(Word::MIN.wrapping_sub(modulus.inv_mod2k(Word::BITS as usize).limbs[0].0));
         let r3 = montgomery_reduction(&r2.square_wide(), modulus, mod_neg_inv);
+        let mod_leading_zeros = modulus.leading_zeros();
+        let modulus_normalized = modulus.shl_vartime(mod_leading_zeros);
 
         Self {
             modulus: *modulus,
@@ -46,11 +73,48 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
             r2,
             r3,
             mod_neg_inv,
+            mod_leading_zeros,
+            modulus_normalized,
+            mod_leading_reciprocal: modulus_leading_reciprocal(&modulus_normalized),
         }
     }
 }
 
 /// A residue represented using `LIMBS` limbs. The odd modulus of this residue is set at runtime.
+///
+/// The arithmetic operators (`+`, `-`, `*`, unary `-` and their assigning versions) perform
+/// the corresponding operations mod the modulus. Both operands of a binary operator must have
+/// been created with the same [`DynResidueParams`]: mixing moduli is a logic error, which is
+/// only caught by debug assertions.
+///
+/// This is the runtime counterpart of [`Residue`][super::constant_mod::Residue]: the Montgomery
+/// parameters are computed once with [`DynResidueParams::new`] and carried by every residue,
+/// so repeated arithmetic modulo a value only known at runtime (such as the prime factors of
+/// an RSA key, see [`crt_exp`]) avoids a full reduction after each operation.
+///
+/// ```
+/// use crypto_bigint::{
+///     modular::runtime_mod::{DynResidue, DynResidueParams},
+///     U256,
+/// };
+///
+/// // The modulus only needs to be known at runtime
+/// let modulus =
+///     U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+/// let params = DynResidueParams::new(&modulus);
+///
+/// let x = DynResidue::new(&U256::from(3u8), params);
+/// let y = DynResidue::new(&U256::from(5u8), params);
+///
+/// assert_eq!((x * y + x).retrieve(), U256::from(18u8));
+/// assert_eq!(x.pow(&U256::from(4u8)).retrieve(), U256::from(81u8));
+/// assert_eq!((x * x.invert().0).retrieve(), U256::ONE);
+/// ```
+///
+/// As for [`Residue`][super::constant_mod::Residue], the Montgomery form is always kept fully
+/// reduced, so each residue has a unique representation and equality (`==` as well as
+/// [`ConstantTimeEq::ct_eq`]) compares the represented values regardless of how the residues
+/// were obtained.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DynResidue<const LIMBS: usize> {
     montgomery_form: Uint<LIMBS>,
@@ -59,6 +123,9 @@ pub struct DynResidue<const LIMBS: usize> {
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
     /// Instantiates a new `Residue` that represents this `integer` mod `MOD`.
+    ///
+    /// `integer` does not need to be reduced: any value is reduced mod `MOD`
+    /// as part of the conversion into Montgomery form.
     pub const fn new(integer: &Uint<LIMBS>, residue_params: DynResidueParams<LIMBS>) -> Self {
         let product = integer.mul_wide(&residue_params.r2);
         let montgomery_form = montgomery_reduction(
@@ -82,6 +149,123 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
         )
     }
 
+    /// Returns the internal Montgomery form of this residue, i.e. `self * R mod modulus`,
+    /// for interoperability with other implementations using the same representation.
+    pub const fn as_montgomery(&self) -> &Uint<LIMBS> {
+        &self.montgomery_form
+    }
+
+    /// Creates a residue directly from its Montgomery form `value = x * R mod modulus`,
+    /// without any conversion.
+    ///
+    /// **WARNING**: this is an escape hatch for interoperability. The caller must
+    /// guarantee that `value` is a valid Montgomery representative for the modulus of
+    /// `residue_params`, i.e. that it was computed with the same `R`
+    /// (`2^Uint::<LIMBS>::BITS`) and is fully reduced (less than the modulus). Otherwise
+    /// all the operations on the resulting residue, including equality, silently produce
+    /// wrong results. Only the reduction is checked, and only by a debug assertion.
+    pub const fn from_montgomery_raw(
+        value: Uint<LIMBS>,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> Self {
+        debug_assert!(
+            Uint::ct_lt(&value, &residue_params.modulus).is_true_vartime(),
+            "Montgomery form must be reduced"
+        );
+        Self {
+            montgomery_form: value,
+            residue_params,
+        }
+    }
+
+    /// Retrieves the integers encoded in each of the `values`, guaranteed to be reduced.
+    ///
+    /// This is equivalent to calling [`DynResidue::retrieve`] on each of them.
+    pub const fn retrieve_array<const N: usize>(values: &[Self; N]) -> [Uint<LIMBS>; N] {
+        let mut out = [Uint::ZERO; N];
+        let mut i = 0;
+        while i < N {
+            out[i] = values[i].retrieve();
+            i += 1;
+        }
+        out
+    }
+
+    /// Retrieves the integers encoded in each of the `values`, guaranteed to be reduced.
+    ///
+    /// This is equivalent to calling [`DynResidue::retrieve`] on each of them.
+    #[cfg(feature = "alloc")]
+    pub fn retrieve_batch(values: &[Self]) -> Vec<Uint<LIMBS>> {
+        values.iter().map(Self::retrieve).collect()
+    }
+
+    /// Converts this residue into one with the given parameters, going through the
+    /// canonical value `x` of `self`, i.e. the result represents `x` mod the new modulus.
+    ///
+    /// `x` is reduced again if it is not less than the new modulus; see
+    /// [`DynResidue::checked_cast_to`] for a variant rejecting such values instead.
+    pub const fn cast_to(&self, residue_params: DynResidueParams<LIMBS>) -> Self {
+        Self::new(&self.retrieve(), residue_params)
+    }
+
+    /// Converts this residue into one with the given parameters, going through the
+    /// canonical value `x` of `self`, returning none if `x` is greater than or equal to
+    /// the new modulus, i.e. if it would be changed by the conversion.
+    ///
+    /// The check is performed in constant time.
+    pub fn checked_cast_to(&self, residue_params: DynResidueParams<LIMBS>) -> CtOption<Self> {
+        let integer = self.retrieve();
+        CtOption::new(
+            Self::new(&integer, residue_params),
+            Uint::ct_lt(&integer, &residue_params.modulus).into(),
+        )
+    }
+
+    /// Instantiates a new `DynResidue` that represents `value` modulo the modulus of
+    /// `residue_params`, where `value` may be greater than or equal to the modulus.
+    ///
+    /// This is equivalent to [`DynResidue::new`], and exists to make the
+    /// reduction of arbitrary inputs explicit at the call site.
+    pub const fn from_uint_reduced(
+        value: Uint<LIMBS>,
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> Self {
+        Self::new(&value, residue_params)
+    }
+
+    /// Instantiates a new `DynResidue` that represents the double-width value
+    /// `lo + hi * 2^BITS` modulo the modulus of `residue_params`, given as the `(lo, hi)` pair
+    /// returned by [`Uint::mul_wide`].
+    ///
+    /// The value is Montgomery-reduced directly and then moved into Montgomery form with a
+    /// single multiplication by the `R^3` stored in `residue_params`, without first reducing
+    /// it with a wide division.
+    ///
+    /// `hi` must be less than the modulus, which always holds for the product of two reduced
+    /// values. This is only checked by a debug assertion.
+    pub const fn from_wide(
+        value: (Uint<LIMBS>, Uint<LIMBS>),
+        residue_params: DynResidueParams<LIMBS>,
+    ) -> Self {
+        debug_assert!(
+            Uint::ct_lt(&value.1, &residue_params.modulus).is_true_vartime(),
+            "high half must be less than the modulus"
+        );
+        let reduced =
+            montgomery_reduction(&value, &residue_params.modulus, residue_params.mod_neg_inv);
+        let montgomery_form = mul_montgomery_form(
+            &reduced,
+            &residue_params.r3,
+            &residue_params.modulus,
+            residue_params.mod_neg_inv,
+        );
+
+        Self {
+            montgomery_form,
+            residue_params,
+        }
+    }
+
     /// Instantiates a new `Residue` that represents zero.
     pub const fn zero(residue_params: DynResidueParams<LIMBS>) -> Self {
         Self {
@@ -105,3 +289,144 @@ impl<const LIMBS: usize> Retrieve for DynResidue<LIMBS> {
         self.retrieve()
     }
 }
+
+impl<const LIMBS: usize> ConstantTimeEq for DynResidue<LIMBS> {
+    /// Compares the Montgomery forms directly, which is correct because they are always
+    /// reduced: see [`DynResidue`]. Residues with different moduli are never equal.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ConstantTimeEq::ct_eq(&self.montgomery_form, &other.montgomery_form)
+            & ConstantTimeEq::ct_eq(&self.residue_params.modulus, &other.residue_params.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynResidue, DynResidueParams};
+    use crate::U256;
+    use subtle::ConstantTimeEq;
+
+    const MODULUS: U256 =
+        U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+    const X: U256 =
+        U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+    #[test]
+    fn montgomery_raw() {
+        let params = DynResidueParams::new(&MODULUS);
+        let x = DynResidue::new(&X, params);
+        assert_eq!(DynResidue::one(params).as_montgomery(), &params.r);
+        assert_eq!(
+            DynResidue::from_montgomery_raw(*x.as_montgomery(), params),
+            x
+        );
+
+        // `R^2` in Montgomery form represents `R^2 * R^-1 = R`
+        assert_eq!(
+            DynResidue::from_montgomery_raw(params.r2, params).retrieve(),
+            params.r
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Montgomery form must be reduced")]
+    fn montgomery_raw_unreduced() {
+        let params = DynResidueParams::new(&MODULUS);
+        let _ = DynResidue::from_montgomery_raw(MODULUS, params);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "high half must be less than the modulus")]
+    fn from_wide_unreduced() {
+        let params = DynResidueParams::new(&MODULUS);
+        let _ = DynResidue::from_wide((U256::ZERO, MODULUS), params);
+    }
+
+    #[test]
+    fn retrieve_array() {
+        let params = DynResidueParams::new(&MODULUS);
+        let values = [
+            DynResidue::zero(params),
+            DynResidue::one(params),
+            DynResidue::new(&X, params),
+        ];
+        assert_eq!(
+            DynResidue::retrieve_array(&values),
+            [U256::ZERO, U256::ONE, X]
+        );
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            DynResidue::retrieve_batch(&values),
+            [U256::ZERO, U256::ONE, X]
+        );
+    }
+
+    #[test]
+    fn eq_regardless_of_construction() {
+        let params = DynResidueParams::new(&MODULUS);
+        let x = DynResidue::new(&X, params);
+        let one = DynResidue::one(params);
+        let zero = DynResidue::zero(params);
+        let two = DynResidue::new(&U256::from_u8(2), params);
+
+        let equivalents = [
+            DynResidue::from_wide((X, U256::ZERO), params),
+            DynResidue::from_wide(X.mul_wide(&U256::ONE), params),
+            x * one,
+            (x + one) - one,
+            x - zero,
+            -(-x),
+            x.pow(&U256::ONE),
+            x.invert().0.invert().0,
+            (x * two) * two.invert().0,
+            zero.mul_add(&zero, &x),
+        ];
+
+        for y in equivalents {
+            assert!(y.montgomery_form < MODULUS);
+            assert!(bool::from(y.ct_eq(&x)));
+            assert_eq!(y, x);
+        }
+
+        assert_eq!(
+            DynResidue::new(&U256::MAX, params),
+            DynResidue::new(&U256::MAX.wrapping_sub(&MODULUS), params)
+        );
+        assert_eq!(DynResidue::new(&MODULUS, params), zero);
+        assert_eq!(x - x, zero);
+        assert!(!bool::from((x + one).ct_eq(&x)));
+
+        // The same value modulo a different modulus is a different residue
+        let other = DynResidueParams::new(&MODULUS.wrapping_sub(&U256::from_u8(2)));
+        assert!(!bool::from(
+            DynResidue::new(&U256::ONE, other).ct_eq(&DynResidue::new(&U256::ONE, params))
+        ));
+    }
+
+    #[test]
+    fn cast_to() {
+        let field = DynResidueParams::new(&U256::from_be_hex(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        ));
+        let scalars = DynResidueParams::new(&MODULUS);
+
+        let x = DynResidue::new(&X, field);
+        let y = x.cast_to(scalars);
+        assert_eq!(y.retrieve(), X);
+        assert_eq!(x.checked_cast_to(scalars).unwrap(), y);
+        assert_eq!(y.cast_to(field), x);
+
+        let minus_one = -DynResidue::one(field);
+        assert_eq!(
+            minus_one.cast_to(scalars).retrieve(),
+            field
+                .modulus
+                .wrapping_sub(&U256::ONE)
+                .wrapping_sub(&MODULUS)
+        );
+        assert!(bool::from(minus_one.checked_cast_to(scalars).is_none()));
+    }
+}
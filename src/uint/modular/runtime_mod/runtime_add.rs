@@ -1,6 +1,6 @@
 use core::ops::{Add, AddAssign};
 
-use crate::modular::add::add_montgomery_form;
+use crate::modular::add::{add_montgomery_form, shl_montgomery_form};
 
 use super::DynResidue;
 
@@ -16,6 +16,28 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Computes `self + self`, i.e. doubles `self`.
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Computes `self * 2^k`.
+    ///
+    /// This is computed using `k` modular doublings and avoids a general
+    /// modular multiplication.
+    ///
+    /// NOTE: this operation is variable time with respect to `k` *ONLY*.
+    pub const fn shl_mod(&self, k: usize) -> Self {
+        Self {
+            montgomery_form: shl_montgomery_form(
+                &self.montgomery_form,
+                k,
+                &self.residue_params.modulus,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
 }
 
 impl<const LIMBS: usize> Add<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
@@ -89,4 +111,34 @@ mod tests {
 
         assert_eq!(expected, x_mod.retrieve());
     }
+
+    #[test]
+    fn shl_mod() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        ));
+
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let x_mod = DynResidue::new(&x, params);
+
+        for k in [0, 1, 64, 255] {
+            let pow2_mod = DynResidue::new(&U256::ONE.shl_vartime(k), params);
+            assert_eq!(x_mod.shl_mod(k), x_mod.mul(&pow2_mod));
+        }
+    }
+
+    #[test]
+    fn double() {
+        let modulus =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        let params = DynResidueParams::new(&modulus);
+
+        let x =
+            U256::from_be_hex("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251");
+        let x_mod = DynResidue::new(&x, params);
+
+        assert_eq!(x_mod.double(), x_mod + x_mod);
+        assert_eq!(x_mod.double().retrieve(), x.add_mod(&x, &modulus));
+    }
 }
@@ -0,0 +1,95 @@
+use super::{DynResidue, DynResidueParams};
+use crate::{Concat, Split, Uint};
+
+/// Computes `base^d mod (p * q)` using the Chinese remainder theorem,
+/// given the components of an RSA private key:
+/// - `dp = d mod (p - 1)`,
+/// - `dq = d mod (q - 1)`,
+/// - `qinv = q^-1 mod p`.
+///
+/// This performs two exponentiations with half-width moduli followed by Garner's
+/// recombination, which is roughly four times faster than a single exponentiation
+/// modulo `p * q`.
+///
+/// `p` and `q` must be distinct odd primes, and `base` must be less than `p * q`.
+pub fn crt_exp<const LIMBS: usize, const HALF_LIMBS: usize>(
+    base: &Uint<LIMBS>,
+    dp: &Uint<HALF_LIMBS>,
+    dq: &Uint<HALF_LIMBS>,
+    p: &DynResidueParams<HALF_LIMBS>,
+    q: &DynResidueParams<HALF_LIMBS>,
+    qinv: &Uint<HALF_LIMBS>,
+) -> Uint<LIMBS>
+where
+    Uint<LIMBS>: Split<Output = Uint<HALF_LIMBS>>,
+    Uint<HALF_LIMBS>: Concat<Output = Uint<LIMBS>>,
+{
+    let (hi, lo) = Split::split(base);
+    let reduce = |params: &DynResidueParams<HALF_LIMBS>| {
+        let (rem, _) = Uint::const_rem_wide((lo, hi), &params.modulus);
+        DynResidue::new(&rem, *params)
+    };
+
+    let m1 = reduce(p).pow(dp);
+    let m2 = reduce(q).pow(dq).retrieve();
+
+    // Garner's recombination: `h = qinv * (m1 - m2) mod p`, and the result is `m2 + h * q`,
+    // which is less than `p * q`, so it cannot overflow.
+    let h = ((m1 - DynResidue::new(&m2, *p)) * DynResidue::new(qinv, *p)).retrieve();
+    let (hq_lo, hq_hi) = h.mul_wide(&q.modulus);
+    Concat::concat(&hq_hi, &hq_lo).wrapping_add(&Concat::concat(&Uint::ZERO, &m2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crt_exp;
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        U128, U64,
+    };
+
+    // A toy RSA key with `e = 65537`
+    const P: U64 = U64::from_be_hex("ffffffffffffffc5");
+    const Q: U64 = U64::from_be_hex("ffffffffffffffad");
+    const N: U128 = U128::from_be_hex("ffffffffffffff720000000000001321");
+    const D: U128 = U128::from_be_hex("93c96c3693c96be3727c8d83727c98e1");
+    const DP: U64 = U64::from_be_hex("15b1ea4e15b1ea49");
+    const DQ: U64 = U64::from_be_hex("f0940f6bf0940f1d");
+    const QINV: U64 = U64::from_be_hex("3555555555555549");
+
+    #[test]
+    fn crt_exp_matches_pow() {
+        let n_params = DynResidueParams::new(&N);
+        let (p_params, q_params) = (DynResidueParams::new(&P), DynResidueParams::new(&Q));
+
+        let bases = [
+            U128::ZERO,
+            U128::ONE,
+            U128::from_u64(0xdead_beef),
+            U128::from_be_hex("0123456789abcdef0123456789abcdef"),
+            N.wrapping_sub(&U128::ONE),
+        ];
+
+        for base in bases {
+            let expected = DynResidue::new(&base, n_params).pow(&D).retrieve();
+            let actual = crt_exp(&base, &DP, &DQ, &p_params, &q_params, &QINV);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn crt_exp_decrypts() {
+        let n_params = DynResidueParams::new(&N);
+        let (p_params, q_params) = (DynResidueParams::new(&P), DynResidueParams::new(&Q));
+
+        let message = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let ciphertext = DynResidue::new(&message, n_params)
+            .pow(&U128::from_u32(65537))
+            .retrieve();
+
+        assert_eq!(
+            crt_exp(&ciphertext, &DP, &DQ, &p_params, &q_params, &QINV),
+            message
+        );
+    }
+}
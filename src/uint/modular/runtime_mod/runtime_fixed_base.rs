@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+
+use crate::{
+    modular::{
+        mul::mul_montgomery_form,
+        pow::{compute_powers, select_power, WINDOW, WINDOW_MASK},
+    },
+    Limb, Uint,
+};
+
+use super::{DynResidue, DynResidueParams};
+
+/// A table of precomputed powers of a fixed base, for raising it to many different exponents.
+///
+/// Created with [`DynResidue::precompute_base`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedBaseTable<const LIMBS: usize> {
+    // powers[j][d] contains base^(d * 2^(WINDOW * j)) in Montgomery form
+    powers: Vec<[Uint<LIMBS>; 1 << WINDOW]>,
+    residue_params: DynResidueParams<LIMBS>,
+}
+
+impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// Precomputes a table of powers of `self` for fixed-base exponentiation with
+    /// [`FixedBaseTable::pow`].
+    ///
+    /// The table holds `2^4` powers for each 4-bit window of the exponent, i.e.
+    /// `4 * Self::BITS` residues in total. Building it costs about as much as four calls to
+    /// [`DynResidue::pow`], and each exponentiation using it needs no squarings at all.
+    pub fn precompute_base(&self) -> FixedBaseTable<LIMBS> {
+        let params = &self.residue_params;
+        let mut powers = Vec::with_capacity(Uint::<LIMBS>::BITS / WINDOW);
+
+        // base^(2^(WINDOW * j))
+        let mut base = self.montgomery_form;
+        for _ in 0..Uint::<LIMBS>::BITS / WINDOW {
+            let row = compute_powers(&base, &params.modulus, &params.r, params.mod_neg_inv);
+            base = mul_montgomery_form(
+                &row[(1 << WINDOW) - 1],
+                &base,
+                &params.modulus,
+                params.mod_neg_inv,
+            );
+            powers.push(row);
+        }
+
+        FixedBaseTable {
+            powers,
+            residue_params: self.residue_params,
+        }
+    }
+}
+
+impl<const LIMBS: usize> FixedBaseTable<LIMBS> {
+    /// Raises the base this table was computed for to the `exponent` power.
+    ///
+    /// This performs one multiplication and one constant-time table lookup
+    /// per 4-bit window of `exponent`.
+    pub fn pow(&self, exponent: &Uint<LIMBS>) -> DynResidue<LIMBS> {
+        let params = &self.residue_params;
+        let mut z = params.r; // 1 in Montgomery form
+
+        for (j, row) in self.powers.iter().enumerate() {
+            let bit = j * WINDOW;
+            let idx = (exponent.limbs[bit / Limb::BITS].0 >> (bit % Limb::BITS)) & WINDOW_MASK;
+
+            let power = select_power(row, idx);
+            z = mul_montgomery_form(&z, &power, &params.modulus, params.mod_neg_inv);
+        }
+
+        DynResidue {
+            montgomery_form: z,
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Returns the parameters of the residues this table produces.
+    pub const fn params(&self) -> &DynResidueParams<LIMBS> {
+        &self.residue_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        U256,
+    };
+
+    #[test]
+    fn test_fixed_base_pow() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B",
+        ));
+        let base = DynResidue::new(
+            &U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4"),
+            params,
+        );
+        let table = base.precompute_base();
+
+        let exponents = [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(65537u32),
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+            U256::MAX,
+        ];
+        for exponent in &exponents {
+            assert_eq!(table.pow(exponent), base.pow(exponent));
+        }
+        assert_eq!(table.params(), &params);
+    }
+}
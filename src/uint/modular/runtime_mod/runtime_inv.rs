@@ -1,6 +1,6 @@
 use subtle::CtOption;
 
-use crate::{modular::inv::inv_montgomery_form, traits::Invert, CtChoice};
+use crate::{modular::inv::inv_montgomery_form, traits::Invert, CtChoice, Uint};
 
 use super::DynResidue;
 
@@ -24,6 +24,15 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
 
         (value, is_some)
     }
+
+    /// Computes the multiplicative inverse of `self` as `self^(p - 2)` using Fermat's
+    /// little theorem, where the modulus `p` must be prime.
+    ///
+    /// This is constant-time by construction, since it is a single call to
+    /// [`DynResidue::pow`]. Zero is mapped to zero.
+    pub const fn invert_fermat(&self) -> Self {
+        self.pow(&self.residue_params.modulus.wrapping_sub(&Uint::from_u8(2)))
+    }
 }
 
 impl<const LIMBS: usize> Invert for DynResidue<LIMBS> {
@@ -33,3 +42,30 @@ impl<const LIMBS: usize> Invert for DynResidue<LIMBS> {
         CtOption::new(value, is_some.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        U256,
+    };
+
+    #[test]
+    fn test_invert_fermat() {
+        // NIST P-256 base field prime
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        ));
+        let x =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        let x_mod = DynResidue::new(&x, params);
+        let inv = x_mod.invert_fermat();
+
+        assert_eq!((x_mod * inv).retrieve(), U256::ONE);
+        assert_eq!(inv, x_mod.invert().0);
+        assert_eq!(
+            DynResidue::zero(params).invert_fermat(),
+            DynResidue::zero(params)
+        );
+    }
+}
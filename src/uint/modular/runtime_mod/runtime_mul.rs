@@ -1,8 +1,12 @@
 use core::ops::{Mul, MulAssign};
 
 use crate::{
-    modular::mul::{mul_montgomery_form, square_montgomery_form},
+    modular::mul::{
+        mul_add_montgomery_form, mul_limb_montgomery_form, mul_montgomery_form,
+        square_montgomery_form,
+    },
     traits::Square,
+    Limb,
 };
 
 use super::DynResidue;
@@ -21,6 +25,23 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
         }
     }
 
+    /// Computes `self * a + b`.
+    ///
+    /// This performs a single Montgomery reduction, adding `b` to the product before
+    /// the reduction rather than after it, e.g. for Horner's rule polynomial evaluation.
+    pub const fn mul_add(&self, a: &Self, b: &Self) -> Self {
+        Self {
+            montgomery_form: mul_add_montgomery_form(
+                &self.montgomery_form,
+                &a.montgomery_form,
+                &b.montgomery_form,
+                &self.residue_params.modulus,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
     /// Computes the (reduced) square of a residue.
     pub const fn square(&self) -> Self {
         Self {
@@ -32,6 +53,25 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Multiplies by a single limb `rhs`.
+    ///
+    /// This is cheaper than converting `rhs` to a residue and calling [`DynResidue::mul`],
+    /// since the product is only one limb wider than the modulus, and is reduced using a
+    /// normalized modulus and reciprocal precomputed in
+    /// [`DynResidueParams`][super::DynResidueParams].
+    pub const fn mul_small(&self, rhs: Limb) -> Self {
+        Self {
+            montgomery_form: mul_limb_montgomery_form(
+                &self.montgomery_form,
+                rhs,
+                &self.residue_params.modulus_normalized,
+                self.residue_params.mod_leading_zeros,
+                &self.residue_params.mod_leading_reciprocal,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
 }
 
 impl<const LIMBS: usize> Mul<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
@@ -82,3 +122,44 @@ impl<const LIMBS: usize> Square for DynResidue<LIMBS> {
         DynResidue::square(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        Limb, U128,
+    };
+
+    #[test]
+    fn mul_add() {
+        let params = DynResidueParams::new(&U128::from_be_hex("ffffffffffffffffffffffffffffff61"));
+        let x = DynResidue::new(
+            &U128::from_be_hex("0123456789abcdeffedcba9876543210"),
+            params,
+        );
+        let y = DynResidue::new(
+            &U128::from_be_hex("fedcba98765432100123456789abcdef"),
+            params,
+        );
+        let minus_one = -DynResidue::one(params);
+
+        for (a, b) in [(x, y), (y, x), (minus_one, minus_one), (minus_one, x)] {
+            assert_eq!(x.mul_add(&a, &b), x * a + b);
+            assert_eq!(minus_one.mul_add(&a, &b), minus_one * a + b);
+        }
+    }
+
+    #[test]
+    fn mul_small() {
+        let params = DynResidueParams::new(&U128::from_be_hex("ffffffffffffffffffffffffffffff61"));
+        let x = DynResidue::new(
+            &U128::from_be_hex("0123456789abcdeffedcba9876543210"),
+            params,
+        );
+
+        for rhs in [Limb::ZERO, Limb::ONE, Limb(0xff), Limb::MAX] {
+            let expected = x.mul(&DynResidue::new(&U128::from_word(rhs.0), params));
+            assert_eq!(x.mul_small(rhs), expected);
+        }
+    }
+}
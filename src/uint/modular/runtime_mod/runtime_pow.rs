@@ -1,9 +1,40 @@
-use crate::{modular::pow::pow_montgomery_form, PowBoundedExp, Uint};
+use crate::{
+    modular::pow::{
+        pow_montgomery_form, pow_montgomery_form_exponent, pow_montgomery_form_vartime,
+    },
+    Exponent, PowBoundedExp, Uint,
+};
+use subtle::Choice;
+
+#[cfg(feature = "rand_core")]
+use {
+    crate::{
+        modular::pow::{blind_exponent, pow_montgomery_form_wide},
+        Limb, Random,
+    },
+    rand_core::CryptoRngCore,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        modular::pow::{compute_powers, pow_montgomery_form_with_powers},
+        BoxedUint, Pow,
+    },
+    alloc::vec::Vec,
+};
 
 use super::DynResidue;
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
     /// Raises to the `exponent` power.
+    ///
+    /// This is constant time with respect to `exponent`: every window performs the same
+    /// operations, and the table of precomputed powers is scanned in full on each lookup,
+    /// so there are no memory accesses indexed by the exponent.
+    ///
+    /// Any base raised to the zero exponent is one, including `0^0`, and zero raised
+    /// to any nonzero exponent is zero.
     pub const fn pow(&self, exponent: &Uint<LIMBS>) -> DynResidue<LIMBS> {
         self.pow_bounded_exp(exponent, Uint::<LIMBS>::BITS)
     }
@@ -26,6 +57,113 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Raises to the `exponent` power, for any exponent type implementing [`Exponent`],
+    /// such as a [`Uint`] of a different width or a [`BoxedUint`][crate::BoxedUint],
+    /// e.g. an RSA private exponent of the same size as the runtime modulus.
+    ///
+    /// This uses the same constant-time method as [`DynResidue::pow`], taking all
+    /// `exponent.bits_precision()` bits into account.
+    pub fn pow_generic<E: Exponent + ?Sized>(&self, exponent: &E) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_exponent(
+                &self.montgomery_form,
+                exponent,
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Raises to the `exponent` power using a sliding window.
+    ///
+    /// This is faster than [`DynResidue::pow`] for sparse exponents such as `65537`:
+    /// only the `exponent.bits_vartime()` significant bits are processed, and
+    /// runs of zero bits only cost a squaring each.
+    ///
+    /// NOTE: this operation is variable time with respect to `exponent`,
+    /// so it must only be used with public exponents.
+    pub const fn pow_vartime(&self, exponent: &Uint<LIMBS>) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_vartime(
+                &self.montgomery_form,
+                exponent,
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Raises to the `exponent` power, blinding the exponent with a random multiple
+    /// of `group_order` as a side-channel countermeasure.
+    ///
+    /// `group_order` must be a multiple of the multiplicative order of `self`
+    /// (e.g. `p - 1` for a prime modulus `p`, or `λ(n)` for an RSA modulus `n`),
+    /// otherwise the result is not equal to the one of [`DynResidue::pow`].
+    #[cfg(feature = "rand_core")]
+    pub fn pow_blinded(
+        &self,
+        exponent: &Uint<LIMBS>,
+        group_order: &Uint<LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Self {
+        let (exponent, exponent_hi) = blind_exponent(exponent, group_order, Limb::random(rng));
+        Self {
+            montgomery_form: pow_montgomery_form_wide(
+                &self.montgomery_form,
+                &exponent,
+                exponent_hi,
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Raises to each of the `exponents` powers.
+    ///
+    /// The table of powers of `self` is computed once and shared between all the exponents,
+    /// which is faster than calling [`DynResidue::pow`] for each of them.
+    #[cfg(feature = "alloc")]
+    pub fn pow_many(&self, exponents: &[Uint<LIMBS>]) -> Vec<Self> {
+        let powers = compute_powers(
+            &self.montgomery_form,
+            &self.residue_params.modulus,
+            &self.residue_params.r,
+            self.residue_params.mod_neg_inv,
+        );
+        exponents
+            .iter()
+            .map(|exponent| Self {
+                montgomery_form: pow_montgomery_form_with_powers(
+                    &powers,
+                    exponent,
+                    Uint::<LIMBS>::BITS,
+                    &self.residue_params.modulus,
+                    &self.residue_params.r,
+                    self.residue_params.mod_neg_inv,
+                ),
+                residue_params: self.residue_params,
+            })
+            .collect()
+    }
+
+    /// Returns a truthy [`Choice`] if `self` is a square modulo the modulus, which must be an odd prime.
+    ///
+    /// Uses Euler's criterion `self^((p - 1) / 2) == 1`, which is cheaper than computing
+    /// a square root when only its existence matters. Zero is considered a square.
+    pub fn is_square(&self) -> Choice {
+        // `p` is odd, so `(p - 1) / 2 == p >> 1`
+        let exponent = self.residue_params.modulus.shr_vartime(1);
+        let is_one = Uint::ct_eq(&self.pow(&exponent).montgomery_form, &self.residue_params.r);
+        let is_zero = self.montgomery_form.ct_is_nonzero().not();
+        is_one.or(is_zero).into()
+    }
 }
 
 impl<const LIMBS: usize> PowBoundedExp<Uint<LIMBS>> for DynResidue<LIMBS> {
@@ -33,3 +171,44 @@ impl<const LIMBS: usize> PowBoundedExp<Uint<LIMBS>> for DynResidue<LIMBS> {
         self.pow_bounded_exp(exponent, exponent_bits)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> Pow<BoxedUint> for DynResidue<LIMBS> {
+    fn pow(&self, exponent: &BoxedUint) -> Self {
+        self.pow_generic(exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        U256,
+    };
+
+    #[test]
+    fn test_powmod_vartime_dyn() {
+        // Odd composite modulus, as in RSA signature verification
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B",
+        ));
+        let base = DynResidue::new(
+            &U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4"),
+            params,
+        );
+
+        let exponents = [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(3u8),
+            U256::from(65537u64),
+            U256::ONE << 255,
+            U256::MAX,
+            U256::from_be_hex("0000000000000000000000000000000000000000000000000000000100000001"),
+        ];
+
+        for exponent in &exponents {
+            assert_eq!(base.pow_vartime(exponent), base.pow(exponent));
+        }
+    }
+}
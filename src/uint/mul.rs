@@ -71,6 +71,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Perform wrapping multiplication, discarding overflow.
+    ///
+    /// Being a `const fn`, this can compute products of constants (e.g. a cofactor times
+    /// a group order) at compile time.
     pub const fn wrapping_mul(&self, rhs: &Self) -> Self {
         self.mul_wide(rhs).0
     }
@@ -282,6 +285,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wrapping_mul_const() {
+        const COFACTOR: U256 = U256::from_u8(8);
+        const ORDER: U256 =
+            U256::from_be_hex("1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED");
+        const PRODUCT: U256 = COFACTOR.wrapping_mul(&ORDER);
+        assert_eq!(
+            PRODUCT,
+            U256::from_be_hex("80000000000000000000000000000000A6F7CEF517BCE6B2C09318D2E7AE9F68")
+        );
+    }
+
     #[test]
     fn checked_mul_ok() {
         let n = U64::from_u32(0xffff_ffff);
@@ -59,9 +59,44 @@ const fn mac_by_limb<const LIMBS: usize>(
 
 #[cfg(all(test, feature = "rand"))]
 mod tests {
-    use crate::{Limb, NonZero, Random, RandomMod, Uint};
+    use crate::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        Limb, NonZero, Random, RandomMod, Uint, U256,
+    };
     use rand_core::SeedableRng;
 
+    /// Checks `mul_mod_special` against the generic Montgomery multiplication
+    /// for the modulus `2^256 - c`, which must be odd.
+    fn check_mul_mod_special_u256(c: Limb) {
+        let p = U256::ZERO.wrapping_sub(&U256::from_word(c.0));
+        let params = DynResidueParams::new(&p);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let a = U256::random_mod(&mut rng, &NonZero::new(p).unwrap());
+            let b = U256::random_mod(&mut rng, &NonZero::new(p).unwrap());
+
+            let expected = (DynResidue::new(&a, params) * DynResidue::new(&b, params)).retrieve();
+            assert_eq!(a.mul_mod_special(&b, c), expected);
+        }
+    }
+
+    #[test]
+    fn mul_mod_special_matches_residue() {
+        // 2^256 - 189 is the largest 256-bit prime
+        check_mul_mod_special_u256(Limb(189));
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn mul_mod_special_secp256k1() {
+        // secp256k1 base field prime: 2^256 - 2^32 - 977
+        check_mul_mod_special_u256(Limb(0x1_0000_03d1));
+        let p =
+            U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        assert_eq!(p, U256::ZERO.wrapping_sub(&U256::from_word(0x1_0000_03d1)));
+    }
+
     macro_rules! test_mul_mod_special {
         ($size:expr, $test_name:ident) => {
             #[test]
@@ -0,0 +1,228 @@
+//! [`Uint`] signed-digit recodings: non-adjacent form and fixed-window Booth recoding.
+
+use super::Uint;
+use crate::{Limb, Word};
+use alloc::vec::Vec;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes the non-adjacent form (NAF) of `self`.
+    ///
+    /// Returns `Self::BITS + 1` signed digits in `{-1, 0, 1}`, least significant first,
+    /// such that `self = sum(digits[i] * 2^i)` and no two adjacent digits are nonzero.
+    /// On average only a third of the digits are nonzero, compared to half of the bits
+    /// in the binary representation.
+    ///
+    /// The number of digits is fixed, and they are computed without branching on `self`.
+    pub fn to_naf(&self) -> Vec<i8> {
+        let mut digits = Vec::with_capacity(Self::BITS + 1);
+        let mut carry: Word = 0;
+
+        for i in 0..=Self::BITS {
+            let current = self.bit_word(i) + carry;
+            let next = self.bit_word(i + 1);
+
+            // If `current` is odd, the digit is `1` when followed by a zero bit,
+            // and `-1` (carrying one into the next position) when followed by a set bit.
+            let odd = current & 1;
+            digits.push((odd as i8) * (1 - 2 * next as i8));
+            carry = (current >> 1) | (odd & next);
+        }
+
+        digits
+    }
+
+    /// Computes the signed-digit (Booth) recoding of `self` with the given window size,
+    /// as used by constant-time scalar multiplication ladders.
+    ///
+    /// Returns `Self::BITS / window + 1` digits, least significant first, such that
+    /// `self = sum(digits[i] * 2^(i * window))`. Each digit is in
+    /// `[-2^(window - 1), 2^(window - 1))`, except the most significant one which is in
+    /// `[0, 2^(window - 1)]`.
+    ///
+    /// Unlike a width-`w` NAF, whose length depends on the value, the number of digits
+    /// depends only on `Self::BITS` and `window`, so a ladder over them always runs the
+    /// same number of steps. The digits are computed without branching on `self`.
+    ///
+    /// Panics if `window` is not in `1..=15`.
+    pub fn to_signed_digits(&self, window: usize) -> Vec<i16> {
+        assert!(
+            (1..=15).contains(&window),
+            "window size must be between 1 and 15 bits"
+        );
+
+        let count = Self::BITS / window + 1;
+        let half: Word = 1 << (window - 1);
+        let mut digits = Vec::with_capacity(count);
+        let mut carry: Word = 0;
+
+        for i in 0..count {
+            let mut value = carry;
+            for j in 0..window {
+                value += self.bit_word(i * window + j) << j;
+            }
+
+            // Digits of at least `2^(window - 1)` become negative by borrowing from the next
+            // window. The last window includes a zero bit above `Self::BITS`, so it never has
+            // to carry and is kept as is.
+            carry = if i + 1 < count {
+                (value + half) >> window
+            } else {
+                0
+            };
+            digits.push((value as i32 - (carry << window) as i32) as i16);
+        }
+
+        digits
+    }
+
+    /// Returns the bit at position `index` (which may be out of range) as a [`Word`].
+    ///
+    /// NOTE: this is variable time with respect to `index` only.
+    fn bit_word(&self, index: usize) -> Word {
+        if index >= Self::BITS {
+            0
+        } else {
+            (self.limbs[index / Limb::BITS].0 >> (index % Limb::BITS)) & 1
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{Uint, U128, U256, U64};
+    use alloc::vec::Vec;
+
+    fn from_naf<const LIMBS: usize>(digits: &[i8]) -> Uint<LIMBS> {
+        digits
+            .iter()
+            .rev()
+            .fold(Uint::ZERO, |acc, &digit| match digit {
+                0 => acc.wrapping_add(&acc),
+                1 => acc.wrapping_add(&acc).wrapping_add(&Uint::ONE),
+                -1 => acc.wrapping_add(&acc).wrapping_sub(&Uint::ONE),
+                _ => panic!("invalid NAF digit: {}", digit),
+            })
+    }
+
+    fn check_naf<const LIMBS: usize>(x: Uint<LIMBS>) {
+        let naf = x.to_naf();
+        assert_eq!(naf.len(), Uint::<LIMBS>::BITS + 1);
+        assert!(naf.windows(2).all(|pair| pair[0] == 0 || pair[1] == 0));
+
+        // The top digit is multiplied by `2^BITS`, so it vanishes in the wrapping reconstruction
+        assert_eq!(from_naf::<LIMBS>(&naf), x);
+    }
+
+    fn check_signed_digits<const LIMBS: usize>(x: Uint<LIMBS>, window: usize) {
+        let digits = x.to_signed_digits(window);
+        assert_eq!(digits.len(), Uint::<LIMBS>::BITS / window + 1);
+
+        let half = 1i32 << (window - 1);
+        let (top, rest) = digits.split_last().unwrap();
+        assert!(rest.iter().all(|&d| -half <= d as i32 && (d as i32) < half));
+        assert!(0 <= *top as i32 && *top as i32 <= half);
+
+        let value = digits.iter().rev().fold(Uint::<LIMBS>::ZERO, |acc, &d| {
+            let acc = acc.shl_vartime(window);
+            let magnitude = Uint::from_u16(d.unsigned_abs());
+            if d < 0 {
+                acc.wrapping_sub(&magnitude)
+            } else {
+                acc.wrapping_add(&magnitude)
+            }
+        });
+        assert_eq!(value, x);
+    }
+
+    #[test]
+    fn to_signed_digits_small() {
+        // 0b1111_0111 = 7 + 15 * 16 = -1 * 16^2 + 0 * 16 + 7 + 16^2
+        let digits = U64::from(0xf7u8).to_signed_digits(4);
+        let expected: Vec<i16> = [7, -1, 1].into_iter().chain([0; 14]).collect();
+        assert_eq!(digits, expected);
+
+        assert!(U64::ZERO.to_signed_digits(5).iter().all(|&d| d == 0));
+    }
+
+    #[test]
+    fn to_signed_digits_exact() {
+        for x in [1u64, 0x88, 0xdead_beef, 0x8888_8888_8888_8888, u64::MAX] {
+            for window in 1..=15 {
+                let digits = U64::from(x).to_signed_digits(window);
+                let value = digits
+                    .iter()
+                    .rev()
+                    .fold(0i128, |acc, &d| (acc << window) + d as i128);
+                assert_eq!(value, x as i128);
+            }
+        }
+    }
+
+    #[test]
+    fn to_signed_digits_reconstruct() {
+        for window in [1, 2, 4, 5, 7, 8, 15] {
+            check_signed_digits(U64::ONE, window);
+            check_signed_digits(U128::MAX, window);
+            check_signed_digits(
+                U128::from_be_hex("88888888888888887777777777777777"),
+                window,
+            );
+            check_signed_digits(
+                U256::from_be_hex(
+                    "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+                ),
+                window,
+            );
+        }
+    }
+
+    #[test]
+    fn to_signed_digits_fixed_length() {
+        // The number of digits does not depend on the value
+        for window in 1..=15 {
+            let len = U256::ZERO.to_signed_digits(window).len();
+            assert_eq!(U256::ONE.to_signed_digits(window).len(), len);
+            assert_eq!(U256::MAX.to_signed_digits(window).len(), len);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be between 1 and 15 bits")]
+    fn to_signed_digits_window_too_large() {
+        let _ = U64::ONE.to_signed_digits(16);
+    }
+
+    #[test]
+    fn to_naf_small() {
+        // 7 = 8 - 1
+        let naf = U64::from(7u8).to_naf();
+        let expected: Vec<i8> = [-1, 0, 0, 1].into_iter().chain([0; 61]).collect();
+        assert_eq!(naf, expected);
+
+        assert!(U64::ZERO.to_naf().iter().all(|&digit| digit == 0));
+    }
+
+    #[test]
+    fn to_naf_exact() {
+        for x in [1u64, 3, 0xdead_beef, 0x5555_5555_5555_5555, u64::MAX] {
+            let naf = U64::from(x).to_naf();
+            let value = naf
+                .iter()
+                .rev()
+                .fold(0i128, |acc, &digit| 2 * acc + digit as i128);
+            assert_eq!(value, x as i128);
+        }
+    }
+
+    #[test]
+    fn to_naf_reconstruct() {
+        check_naf(U64::ONE);
+        check_naf(U64::from(0xdead_beef_u32));
+        check_naf(U128::MAX);
+        check_naf(U128::from_be_hex("5555555555555555aaaaaaaaaaaaaaaa"));
+        check_naf(U256::from_be_hex(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        ));
+    }
+}
@@ -23,6 +23,14 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     pub const fn neg_mod_special(&self, c: Limb) -> Self {
         Self::ZERO.sub_mod_special(self, c)
     }
+
+    /// Computes `-a mod 2^k` in constant time.
+    ///
+    /// This is a wrapping negation followed by masking the low `k` bits,
+    /// which avoids the general modular reduction.
+    pub const fn neg_mod2k(&self, k: usize) -> Self {
+        Self::ZERO.wrapping_sub(self).rem2k(k)
+    }
 }
 
 impl<const LIMBS: usize> NegMod for Uint<LIMBS> {
@@ -65,4 +73,15 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn neg_mod2k() {
+        let x = U256::from_u8(3);
+
+        assert_eq!(x.neg_mod2k(8), U256::from_u8(0xfd));
+        assert_eq!(x.neg_mod2k(64), U256::from_u64(0xffff_ffff_ffff_fffd));
+        assert_eq!(x.neg_mod2k(256), U256::MAX.wrapping_sub(&U256::from_u8(2)));
+        assert_eq!(U256::ZERO.neg_mod2k(100), U256::ZERO);
+        assert_eq!(x.neg_mod2k(100).add_mod2k(&x, 100), U256::ZERO);
+    }
 }
@@ -0,0 +1,214 @@
+//! [`Uint`] modular exponentiation operations.
+
+use super::Uint;
+use crate::{
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+    CtChoice, Limb, NonZero,
+};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `self^exponent mod modulus` for any nonzero modulus, without having to
+    /// set up the residue types.
+    ///
+    /// Odd moduli use Montgomery arithmetic via [`DynResidue::pow`], while even moduli
+    /// fall back to [`Uint::pow_barrett`], which is somewhat slower. Either way the result
+    /// is canonical and `0^0` is one (or zero for a modulus of one).
+    ///
+    /// This is constant time with respect to `self` and `exponent`, but not with respect
+    /// to whether `modulus` is odd. When raising many values to powers modulo the same
+    /// odd modulus, reusing a [`DynResidueParams`] is more efficient.
+    pub fn pow_mod(&self, exponent: &Self, modulus: &NonZero<Self>) -> Self {
+        let base = self.rem(modulus);
+
+        if modulus.ct_is_odd().is_true_vartime() {
+            let params = DynResidueParams::new(modulus);
+            // The Montgomery parameters do not reduce mod 1, so the result is reduced again
+            return DynResidue::new(&base, params)
+                .pow(exponent)
+                .retrieve()
+                .rem(modulus);
+        }
+
+        self.pow_barrett(exponent, modulus)
+    }
+
+    /// Computes `self^exponent mod modulus` for any nonzero modulus using a
+    /// square-and-multiply ladder with Barrett reduction.
+    ///
+    /// Unlike Montgomery multiplication this does not require the modulus to be odd, so
+    /// it also covers moduli such as `2^k * odd`. The result is canonical and `0^0` is one
+    /// (or zero for a modulus of one).
+    ///
+    /// This is constant time with respect to `self` and `exponent`, but not `modulus`.
+    pub fn pow_barrett(&self, exponent: &Self, modulus: &NonZero<Self>) -> Self {
+        let barrett = Barrett::new(modulus);
+        let base = self.rem(modulus);
+
+        let mut z = Self::ONE.rem(modulus);
+        let mut i = Self::BITS;
+        while i > 0 {
+            i -= 1;
+            z = barrett.reduce(z.mul_wide(&z));
+            let product = barrett.reduce(z.mul_wide(&base));
+            z = Self::ct_select(&z, &product, exponent.bit(i));
+        }
+
+        z
+    }
+}
+
+/// Precomputed values for Barrett reduction modulo an arbitrary nonzero modulus.
+///
+/// The modulus is normalized so that its top bit is set, which keeps the reciprocal
+/// within `BITS + 1` bits and bounds the quotient estimate error by three.
+struct Barrett<const LIMBS: usize> {
+    /// The modulus shifted left by `shift` bits, so that its top bit is set.
+    modulus: Uint<LIMBS>,
+    /// The number of leading zeros of the original modulus.
+    shift: usize,
+    /// `floor((2^(2 * BITS) - 1) / modulus) - 2^BITS`, i.e. the reciprocal without its
+    /// implicit top bit.
+    mu: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> Barrett<LIMBS> {
+    fn new(modulus: &NonZero<Uint<LIMBS>>) -> Self {
+        let shift = Uint::<LIMBS>::BITS - modulus.bits_vartime();
+        let modulus = modulus.shl_vartime(shift);
+
+        // Long division of `2^(2 * BITS) - 1 - 2^BITS * modulus` by the modulus; the high
+        // half is smaller than the modulus, so the quotient fits in `BITS` bits.
+        let mut rem = Uint::MAX.wrapping_sub(&modulus);
+        let mut mu = Uint::ZERO;
+        let mut i = 0;
+        while i < Uint::<LIMBS>::BITS {
+            // Every bit of the low half is set
+            let (doubled, carry) = rem.adc(&rem, Limb::ONE);
+            let (diff, borrow) = doubled.sbb(&modulus, Limb::ZERO);
+            mu = mu.shl_vartime(1);
+            if carry.0 != 0 || borrow.0 == 0 {
+                rem = diff;
+                mu = mu.bitor(&Uint::ONE);
+            } else {
+                rem = doubled;
+            }
+            i += 1;
+        }
+
+        Self { modulus, shift, mu }
+    }
+
+    /// Reduces `lower_upper`, which must be less than the square of the original modulus.
+    fn reduce(&self, lower_upper: (Uint<LIMBS>, Uint<LIMBS>)) -> Uint<LIMBS> {
+        let (lo, hi) = Uint::shl_vartime_wide(lower_upper, self.shift);
+
+        // `floor(hi * (2^BITS + mu) / 2^BITS)` underestimates the quotient by at most three
+        let quotient = hi.wrapping_add(&hi.mul_wide(&self.mu).1);
+        let (qm_lo, qm_hi) = quotient.mul_wide(&self.modulus);
+        let (mut r_lo, borrow) = lo.sbb(&qm_lo, Limb::ZERO);
+        // The remainder is less than `4 * modulus`, so only the lowest high limb is set
+        let mut r_hi = hi.sbb(&qm_hi, borrow).0.limbs[0];
+
+        let mut i = 0;
+        while i < 3 {
+            let (diff, borrow) = r_lo.sbb(&self.modulus, Limb::ZERO);
+            let (diff_hi, borrow) = r_hi.sbb(Limb::ZERO, borrow);
+            let underflow = CtChoice::from_mask(borrow.0);
+            r_lo = Uint::ct_select(&diff, &r_lo, underflow);
+            r_hi = Limb::ct_select(diff_hi, r_hi, underflow);
+            i += 1;
+        }
+
+        r_lo.shr_vartime(self.shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonZero, U256, U64};
+
+    /// Reference square-and-multiply on `u128`.
+    fn pow_mod_u64(base: u64, exponent: u64, modulus: u64) -> u64 {
+        let m = modulus as u128;
+        let mut z = 1 % m;
+        for i in (0..64).rev() {
+            z = z * z % m;
+            if (exponent >> i) & 1 == 1 {
+                z = z * (base as u128 % m) % m;
+            }
+        }
+        z as u64
+    }
+
+    #[test]
+    fn pow_mod_small() {
+        for m in [
+            1u64,
+            2,
+            3,
+            4,
+            10,
+            97,
+            1 << 40,
+            1 << 63,
+            0xffff_ffff_ffff_ffc5,
+            u64::MAX,
+        ] {
+            let modulus = NonZero::new(U64::from_u64(m)).unwrap();
+            for base in [0u64, 1, 2, 3, m - 1, m, u64::MAX] {
+                for exponent in [0u64, 1, 2, 5, 64, 65537, u64::MAX] {
+                    assert_eq!(
+                        U64::from_u64(base).pow_barrett(&U64::from_u64(exponent), &modulus),
+                        U64::from_u64(pow_mod_u64(base, exponent, m)),
+                    );
+                    assert_eq!(
+                        U64::from_u64(base).pow_mod(&U64::from_u64(exponent), &modulus),
+                        U64::from_u64(pow_mod_u64(base, exponent, m)),
+                        "{}^{} mod {}",
+                        base,
+                        exponent,
+                        m
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pow_mod_odd_and_even() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let exponent =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let odd =
+            U256::from_be_hex("9CC24C5DF431A864188AB905AC751B727C9447A8E99E6366E1AD78A21E8D882B");
+        assert_eq!(
+            base.pow_mod(&exponent, &NonZero::new(odd).unwrap()),
+            U256::from_be_hex("3681BC0FEA2E5D394EB178155A127B0FD2EF405486D354251C385BDD51B9D421")
+        );
+
+        let even =
+            U256::from_be_hex("398498BBE86350C83115720B58EA36E4F9288F51D33CC6CDC35AF1443D1B1056");
+        assert_eq!(
+            base.pow_mod(&exponent, &NonZero::new(even).unwrap()),
+            U256::from_be_hex("37C1E557C99683AF3A1153D226A3CFFDB2F832C85984F0C4014141E05729468C")
+        );
+
+        assert_eq!(
+            base.pow_barrett(&exponent, &NonZero::new(odd).unwrap()),
+            base.pow_mod(&exponent, &NonZero::new(odd).unwrap())
+        );
+        assert_eq!(
+            base.pow_barrett(&exponent, &NonZero::new(even).unwrap()),
+            U256::from_be_hex("37C1E557C99683AF3A1153D226A3CFFDB2F832C85984F0C4014141E05729468C")
+        );
+
+        let power_of_two = NonZero::new(U256::ONE.shl_vartime(200)).unwrap();
+        assert_eq!(
+            base.wrapping_add(&U256::ONE)
+                .pow_mod(&exponent, &power_of_two),
+            U256::from_be_hex("00000000000000579863A1496ACE4553F5D6F6BB4833952567FFADCE98925755")
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! [`Uint`] decomposition into base `2^W` digits.
+
+use super::Uint;
+use crate::{Limb, Word};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Splits `self` into base `2^W` digits, least significant first, as used by
+    /// fixed-window exponentiation.
+    ///
+    /// Returns an array of `N` digits along with the number of digits actually used,
+    /// which is always `ceil(Self::BITS / W)`; the remaining entries are zero.
+    /// When `W` does not divide `Self::BITS`, the most significant digit has fewer
+    /// than `W` bits.
+    ///
+    /// The digits are extracted without branching on the value of `self`.
+    ///
+    /// Panics if `W` is not in `1..=16`, or if `N` is less than `ceil(Self::BITS / W)`.
+    pub const fn to_base_2w<const W: usize, const N: usize>(&self) -> ([u16; N], usize) {
+        assert!(
+            W >= 1 && W <= 16,
+            "window size must be between 1 and 16 bits"
+        );
+        let count = (Self::BITS + W - 1) / W;
+        assert!(N >= count, "digit array is too small");
+
+        let mask: Word = (1 << W) - 1;
+        let mut digits = [0u16; N];
+        let mut i = 0;
+
+        while i < count {
+            let pos = i * W;
+            let limb = pos / Limb::BITS;
+            let offset = pos % Limb::BITS;
+
+            let mut digit = self.limbs[limb].0 >> offset;
+            // The digit may straddle two limbs
+            if offset + W > Limb::BITS && limb + 1 < LIMBS {
+                digit |= self.limbs[limb + 1].0 << (Limb::BITS - offset);
+            }
+
+            digits[i] = (digit & mask) as u16;
+            i += 1;
+        }
+
+        (digits, count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Uint, U128, U192, U256, U64};
+
+    fn from_base_2w<const LIMBS: usize>(digits: &[u16], w: usize) -> Uint<LIMBS> {
+        digits.iter().rev().fold(Uint::ZERO, |acc, &digit| {
+            acc.shl_vartime(w).wrapping_add(&Uint::from_u16(digit))
+        })
+    }
+
+    fn check_base_2w<const LIMBS: usize, const W: usize>(x: Uint<LIMBS>) {
+        let (digits, count) = x.to_base_2w::<W, 256>();
+        assert_eq!(count, (Uint::<LIMBS>::BITS + W - 1) / W);
+        assert!(digits[..count]
+            .iter()
+            .all(|&digit| (digit as usize) < 1 << W));
+        assert!(digits[count..].iter().all(|&digit| digit == 0));
+        assert_eq!(from_base_2w::<LIMBS>(&digits[..count], W), x);
+    }
+
+    #[test]
+    fn to_base_2w_small() {
+        let (digits, count) = U64::from_u64(0x1234_5678_9abc_def0).to_base_2w::<4, 16>();
+        assert_eq!(count, 16);
+        assert_eq!(
+            digits,
+            [0x0, 0xf, 0xe, 0xd, 0xc, 0xb, 0xa, 0x9, 0x8, 0x7, 0x6, 0x5, 0x4, 0x3, 0x2, 0x1]
+        );
+
+        // 64 is not a multiple of 5, so the top digit only has 4 bits
+        let (digits, count) = U64::MAX.to_base_2w::<5, 16>();
+        assert_eq!(count, 13);
+        assert_eq!(digits[..12], [0x1f; 12]);
+        assert_eq!(digits[12..], [0xf, 0, 0, 0]);
+    }
+
+    #[test]
+    fn to_base_2w_reconstruct() {
+        let x =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+        check_base_2w::<{ U256::LIMBS }, 1>(x);
+        check_base_2w::<{ U256::LIMBS }, 3>(x);
+        check_base_2w::<{ U256::LIMBS }, 4>(x);
+        check_base_2w::<{ U256::LIMBS }, 5>(x);
+        check_base_2w::<{ U256::LIMBS }, 7>(x);
+        check_base_2w::<{ U256::LIMBS }, 13>(x);
+        check_base_2w::<{ U256::LIMBS }, 16>(x);
+
+        check_base_2w::<{ U128::LIMBS }, 6>(U128::MAX);
+        check_base_2w::<{ U192::LIMBS }, 11>(U192::from_u64(0xdead_beef));
+        check_base_2w::<{ U64::LIMBS }, 9>(U64::ZERO);
+    }
+
+    #[test]
+    fn to_base_2w_const() {
+        const DIGITS: ([u16; 4], usize) = U64::from_u8(0xab).to_base_2w::<16, 4>();
+        assert_eq!(DIGITS, ([0xab, 0, 0, 0], 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "digit array is too small")]
+    fn to_base_2w_too_small() {
+        let _ = U128::ONE.to_base_2w::<4, 31>();
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be between 1 and 16 bits")]
+    fn to_base_2w_window_too_large() {
+        let _ = U128::ONE.to_base_2w::<17, 8>();
+    }
+}
@@ -3,7 +3,11 @@
 use super::Uint;
 use crate::{Limb, NonZero, Random, RandomMod};
 use rand_core::CryptoRngCore;
-use subtle::ConstantTimeLess;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+
+/// [`Uint::random_mod_ct`] performs enough sampling rounds for all of them to fail with
+/// probability at most `2^-RANDOM_MOD_CT_SECURITY_BITS`.
+const RANDOM_MOD_CT_SECURITY_BITS: usize = 128;
 
 impl<const LIMBS: usize> Random for Uint<LIMBS> {
     /// Generate a cryptographically secure random [`Uint`].
@@ -16,6 +20,82 @@ impl<const LIMBS: usize> Random for Uint<LIMBS> {
 
         limbs.into()
     }
+
+    /// Generate a cryptographically secure random [`Uint`], returning an error
+    /// if `rng` fails.
+    fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+
+        for limb in &mut limbs {
+            *limb = Limb::try_random(rng)?;
+        }
+
+        Ok(limbs.into())
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Generate a cryptographically secure random [`Uint`] which is less than
+    /// a given `modulus`, reading a fixed amount of randomness.
+    ///
+    /// Unlike [`Uint::random_mod`], this performs a fixed number of sampling rounds of
+    /// `n = Self::BITS - modulus.leading_zeros()` bits each, and keeps the first in-range
+    /// sample using constant-time selection. Neither the number of RNG reads nor the
+    /// running time depend on the sampled values, only on `modulus`.
+    ///
+    /// The number of rounds is derived from the count `k` of leading one bits of `modulus`,
+    /// i.e. the leading zeros of its complement once normalized. Since
+    /// `modulus >= 2^n - 2^(n - k)`, a round is out of range with probability at most `2^-k`,
+    /// so `ceil(128 / k)` rounds are all out of range with probability at most `2^-128`.
+    /// Sparse moduli such as `2^(n - 1) + 1` need 128 rounds, dense ones such as
+    /// `2^n - 1` only one.
+    ///
+    /// If every round is out of range, the last sample is reduced by a single subtraction
+    /// of `modulus`. The resulting bias from the uniform distribution is therefore negligible.
+    pub fn random_mod_ct(mut rng: &mut impl CryptoRngCore, modulus: &NonZero<Self>) -> Self {
+        let n_bits = Self::BITS - modulus.as_ref().leading_zeros();
+        let n_limbs = (n_bits + Limb::BITS - 1) / Limb::BITS;
+        let mask = Limb::MAX >> (Limb::BITS * n_limbs - n_bits);
+
+        // `modulus >= 2^n_bits - 2^(n_bits - leading_ones)`, and its top bit is set
+        let leading_ones = modulus
+            .as_ref()
+            .shl_vartime(Self::BITS - n_bits)
+            .not()
+            .leading_zeros();
+        let rounds = (RANDOM_MOD_CT_SECURITY_BITS + leading_ones - 1) / leading_ones;
+
+        let mut n = Self::ZERO;
+        let mut result = Self::ZERO;
+        let mut found = Choice::from(0);
+
+        for _ in 0..rounds {
+            for i in 0..n_limbs {
+                n.limbs[i] = Limb::random(&mut rng);
+            }
+            n.limbs[n_limbs - 1] = n.limbs[n_limbs - 1] & mask;
+
+            let in_range = n.ct_lt(modulus);
+            result.conditional_assign(&n, in_range & !found);
+            found |= in_range;
+        }
+
+        // `n < 2^n_bits <= 2 * modulus`, so one subtraction brings it in range
+        let fallback = n.wrapping_sub(modulus);
+        Self::conditional_select(&fallback, &result, found)
+    }
+
+    /// Generate a cryptographically secure random [`Uint`] in the range `[low, high)`,
+    /// using rejection sampling on `high - low` as in [`Uint::random_mod`].
+    ///
+    /// Panics if `low >= high`, since the range is then empty.
+    pub fn random_in_range(rng: &mut impl CryptoRngCore, low: &Self, high: &Self) -> Self {
+        assert!(low < high, "range must not be empty");
+
+        // `low < high`, so the width is nonzero
+        let width = NonZero::from_uint(high.wrapping_sub(low));
+        low.wrapping_add(&Self::random_mod(rng, &width))
+    }
 }
 
 impl<const LIMBS: usize> RandomMod for Uint<LIMBS> {
@@ -48,12 +128,92 @@ impl<const LIMBS: usize> RandomMod for Uint<LIMBS> {
             }
         }
     }
+
+    /// Generate a cryptographically secure random [`Uint`] which is less than
+    /// a given `modulus`, returning an error if `rng` fails.
+    ///
+    /// See [`Uint::random_mod`] for details about the sampling method.
+    fn try_random_mod(
+        rng: &mut impl CryptoRngCore,
+        modulus: &NonZero<Self>,
+    ) -> Result<Self, rand_core::Error> {
+        let mut n = Self::ZERO;
+
+        let n_bits = modulus.as_ref().bits_vartime();
+        let n_limbs = (n_bits + Limb::BITS - 1) / Limb::BITS;
+        let mask = Limb::MAX >> (Limb::BITS * n_limbs - n_bits);
+
+        loop {
+            for i in 0..n_limbs {
+                n.limbs[i] = Limb::try_random(rng)?;
+            }
+            n.limbs[n_limbs - 1] = n.limbs[n_limbs - 1] & mask;
+
+            if n.ct_lt(modulus).into() {
+                return Ok(n);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{NonZero, RandomMod, U256};
-    use rand_core::SeedableRng;
+    use crate::{Limb, NonZero, Random, RandomMod, Wrapping, U256};
+    use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+    /// RNG wrapper counting the number of bytes requested.
+    struct CountingRng<R> {
+        inner: R,
+        bytes: usize,
+    }
+
+    impl<R: RngCore> RngCore for CountingRng<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.bytes += 4;
+            self.inner.next_u32()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.bytes += 8;
+            self.inner.next_u64()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.bytes += dest.len();
+            self.inner.fill_bytes(dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.bytes += dest.len();
+            self.inner.try_fill_bytes(dest)
+        }
+    }
+
+    impl<R: CryptoRng> CryptoRng for CountingRng<R> {}
+
+    /// RNG whose fallible methods always return an error, and whose infallible
+    /// methods only ever output zeros.
+    struct FailingRng;
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0)
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            Err(rand_core::Error::new("entropy source failure"))
+        }
+    }
+
+    impl CryptoRng for FailingRng {}
 
     #[test]
     fn random_mod() {
@@ -74,4 +234,116 @@ mod tests {
         // Sanity check that the return value isn't zero
         assert_ne!(res, U256::ZERO);
     }
+
+    #[test]
+    fn random_mod_ct() {
+        // Moduli with the number of rounds their leading one bits call for
+        let moduli = [
+            (U256::ONE, 128),
+            (U256::from(42u8), 128),
+            (U256::from(0x10000000000000001u128), 128),
+            (U256::from(0xe000u16), 43),
+            (U256::MAX, 1),
+            (
+                U256::from_be_hex(
+                    "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                ),
+                4,
+            ),
+        ];
+
+        for (modulus, rounds) in moduli {
+            let modulus = NonZero::new(modulus).unwrap();
+            let expected_bytes = {
+                let n_limbs = (modulus.bits() + Limb::BITS - 1) / Limb::BITS;
+                rounds * n_limbs * Limb::BYTES
+            };
+
+            for seed in 0..4 {
+                let mut rng = CountingRng {
+                    inner: rand_chacha::ChaCha8Rng::seed_from_u64(seed),
+                    bytes: 0,
+                };
+                let res = U256::random_mod_ct(&mut rng, &modulus);
+                assert!(res < *modulus);
+
+                // The amount of randomness consumed depends only on the modulus
+                assert_eq!(rng.bytes, expected_bytes);
+            }
+        }
+    }
+
+    #[test]
+    fn random_mod_ct_matches_first_sample() {
+        let modulus = NonZero::new(U256::from(0x10000000000000001u128)).unwrap();
+
+        // With the same seed, the first in-range sample is the one `random_mod` returns
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let expected = U256::random_mod(&mut rng, &modulus);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        assert_eq!(U256::random_mod_ct(&mut rng, &modulus), expected);
+    }
+
+    #[test]
+    fn random_in_range() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        // Just below and above a power of two
+        let low = U256::ONE.shl_vartime(128).wrapping_sub(&U256::from(3u8));
+        let high = U256::ONE.shl_vartime(128).wrapping_add(&U256::from(3u8));
+        let mut seen = [false; 6];
+        for _ in 0..200 {
+            let x = U256::random_in_range(&mut rng, &low, &high);
+            assert!(low <= x && x < high);
+            seen[x.wrapping_sub(&low).as_words()[0] as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        // Single element range
+        let x = U256::random_in_range(&mut rng, &U256::MAX.wrapping_sub(&U256::ONE), &U256::MAX);
+        assert_eq!(x, U256::MAX.wrapping_sub(&U256::ONE));
+
+        // Full range
+        let x = U256::random_in_range(&mut rng, &U256::ZERO, &U256::MAX);
+        assert!(x < U256::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "range must not be empty")]
+    fn random_in_range_empty() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let _ = U256::random_in_range(&mut rng, &U256::ONE, &U256::ONE);
+    }
+
+    #[test]
+    fn try_random() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        assert!(matches!(U256::try_random(&mut rng), Ok(x) if x != U256::ZERO));
+        assert!(NonZero::<U256>::try_random(&mut rng).is_ok());
+        assert!(Wrapping::<U256>::try_random(&mut rng).is_ok());
+
+        let modulus = NonZero::new(U256::from(42u8)).unwrap();
+        assert!(matches!(
+            U256::try_random_mod(&mut rng, &modulus),
+            Ok(x) if x < U256::from(42u8)
+        ));
+        let modulus = NonZero::new(Limb::from(42u8)).unwrap();
+        assert!(matches!(
+            Limb::try_random_mod(&mut rng, &modulus),
+            Ok(x) if x < Limb::from(42u8)
+        ));
+    }
+
+    #[test]
+    fn try_random_propagates_errors() {
+        assert!(Limb::try_random(&mut FailingRng).is_err());
+        assert!(U256::try_random(&mut FailingRng).is_err());
+        assert!(NonZero::<U256>::try_random(&mut FailingRng).is_err());
+        assert!(Wrapping::<U256>::try_random(&mut FailingRng).is_err());
+
+        let modulus = NonZero::new(U256::from(42u8)).unwrap();
+        assert!(U256::try_random_mod(&mut FailingRng, &modulus).is_err());
+        let modulus = NonZero::new(Limb::from(42u8)).unwrap();
+        assert!(Limb::try_random_mod(&mut FailingRng, &modulus).is_err());
+    }
 }
@@ -1,9 +1,14 @@
 use super::Uint;
+use crate::Limb;
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Construct a `Uint<T>` from the unsigned integer value,
     /// truncating the upper bits if the value is too large to be
     /// represented.
+    ///
+    /// This is a `const fn`, so wider constants can be derived from narrower ones at
+    /// compile time, in which case the value is zero-extended.
     #[inline(always)]
     pub const fn resize<const T: usize>(&self) -> Uint<T> {
         let mut res = Uint::ZERO;
@@ -15,11 +20,36 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
         res
     }
+
+    /// Construct a `Uint<T>` from the unsigned integer value, returning none if it does not
+    /// fit, i.e. if any of the limbs beyond the first `T` is nonzero.
+    ///
+    /// This is constant time; see [`Uint::try_resize`] for a variant returning an
+    /// [`Option`], for public values.
+    pub fn try_into_uint<const T: usize>(&self) -> CtOption<Uint<T>> {
+        let mut high = Limb::ZERO;
+        let mut i = T;
+        while i < LIMBS {
+            high = high.bitor(self.limbs[i]);
+            i += 1;
+        }
+
+        CtOption::new(self.resize(), high.ct_is_nonzero().not().into())
+    }
+
+    /// Construct a `Uint<T>` from the unsigned integer value, returning `None` if it does
+    /// not fit, i.e. if any of the limbs beyond the first `T` is nonzero.
+    ///
+    /// NOTE: this operation is variable time with respect to `self`; use
+    /// [`Uint::try_into_uint`] for secret values.
+    pub fn try_resize<const T: usize>(&self) -> Option<Uint<T>> {
+        self.try_into_uint().into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{U128, U64};
+    use crate::{U128, U256, U512, U64};
 
     #[test]
     fn resize_larger() {
@@ -28,10 +58,40 @@ mod tests {
         assert_eq!(u2, U128::from_be_hex("0000000000000000AAAAAAAABBBBBBBB"));
     }
 
+    #[test]
+    fn resize_const() {
+        const NARROW: U256 =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        const WIDE: U512 = NARROW.resize();
+        const BACK: U256 = WIDE.resize();
+
+        assert_eq!(WIDE.bits(), 256);
+        assert_eq!(WIDE.as_words()[U256::LIMBS..], [0; U256::LIMBS]);
+        assert_eq!(WIDE.as_words()[..U256::LIMBS], NARROW.as_words()[..]);
+        assert_eq!(BACK, NARROW);
+    }
+
     #[test]
     fn resize_smaller() {
         let u = U128::from_be_hex("AAAAAAAABBBBBBBBCCCCCCCCDDDDDDDD");
         let u2: U64 = u.resize();
         assert_eq!(u2, U64::from_be_hex("CCCCCCCCDDDDDDDD"));
     }
+
+    #[test]
+    fn try_resize_smaller() {
+        let u = U128::from_be_hex("0000000000000000CCCCCCCCDDDDDDDD");
+        assert_eq!(u.try_resize::<{ U64::LIMBS }>(), Some(u.resize()));
+        assert_eq!(u.try_into_uint::<{ U64::LIMBS }>().unwrap(), u.resize());
+
+        let u = U128::from_be_hex("0000000000000001CCCCCCCCDDDDDDDD");
+        assert_eq!(u.try_resize::<{ U64::LIMBS }>(), None);
+        assert!(bool::from(u.try_into_uint::<{ U64::LIMBS }>().is_none()));
+    }
+
+    #[test]
+    fn try_resize_larger() {
+        assert_eq!(U64::MAX.try_resize(), Some(U128::from_u64(u64::MAX)));
+        assert_eq!(U64::MAX.try_into_uint().unwrap(), U128::from_u64(u64::MAX));
+    }
 }
@@ -1,7 +1,10 @@
 //! [`Uint`] bitwise left shift operations.
+//!
+//! Shifts of [`Uint`] are logical: vacated bits are always filled with zeros.
 
 use crate::{limb::HI_BIT, CtChoice, Limb, Uint, Word};
 use core::ops::{Shl, ShlAssign};
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self << 1` in constant-time, returning the overflowing bit as a `CtChoice`.
@@ -62,6 +65,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Computes `self << shift`.
     ///
+    /// This is a logical shift: the low bits are filled with zeros, and shifting by
+    /// `Self::BITS` or more returns zero. See [`Uint::checked_shl`] to detect over-shifts.
+    ///
     /// NOTE: this operation is variable time with respect to `n` *ONLY*.
     ///
     /// When used with a fixed `n`, this function is constant-time with respect
@@ -106,6 +112,31 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         (new_lower, upper)
     }
+
+    /// Computes `self << n`, returning the result along with the bits shifted out of the
+    /// top, right-aligned in the second [`Uint`], i.e. `(self << n) >> Self::BITS` as if
+    /// computed with double the width.
+    ///
+    /// The carry can be OR'ed into the next (more significant) [`Uint`] of a buffer shifted
+    /// by the same amount, to chain shifts across a multi-[`Uint`] buffer.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn shl_with_carry(&self, n: usize) -> (Self, Self) {
+        Self::shl_vartime_wide((*self, Self::ZERO), n)
+    }
+
+    /// Computes `self << n`, returning none if `n >= Self::BITS`.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub fn checked_shl(&self, n: usize) -> CtOption<Self> {
+        CtOption::new(self.shl_vartime(n), ((n < Self::BITS) as u8).into())
+    }
 }
 
 impl<const LIMBS: usize> Shl<usize> for Uint<LIMBS> {
@@ -197,6 +228,40 @@ mod tests {
         assert_eq!(N << 256, U256::default());
     }
 
+    #[test]
+    fn shl_with_carry() {
+        let x = U128::from_be_hex("0123456789abcdef0011223344556677");
+        assert_eq!(x.shl_with_carry(0), (x, U128::ZERO));
+        assert_eq!(
+            x.shl_with_carry(8),
+            (
+                U128::from_be_hex("23456789abcdef001122334455667700"),
+                U128::from_u8(0x01)
+            )
+        );
+        assert_eq!(x.shl_with_carry(128), (U128::ZERO, x));
+        assert_eq!(x.shl_with_carry(300), (U128::ZERO, U128::ZERO));
+
+        // Chaining across a two-element buffer matches a shift of the wider integer
+        let wide =
+            U256::from_be_hex("fedcba98765432100123456789abcdef0123456789abcdeffedcba9876543210");
+        let (hi, lo) = wide.split();
+        for n in [1, 63, 64, 65, 127] {
+            let (new_lo, carry) = lo.shl_with_carry(n);
+            let (new_hi, _) = hi.shl_with_carry(n);
+            assert_eq!(U256::from((new_lo, new_hi.bitor(&carry))), wide << n);
+        }
+    }
+
+    #[test]
+    fn checked_shl() {
+        assert_eq!(N.checked_shl(1).unwrap(), TWO_N);
+        assert_eq!(N.checked_shl(65).unwrap(), SIXTY_FIVE);
+        assert_eq!(U256::ONE.checked_shl(255).unwrap(), U256::ONE << 255);
+        assert!(bool::from(U256::ONE.checked_shl(256).is_none()));
+        assert!(bool::from(U256::ONE.checked_shl(usize::MAX).is_none()));
+    }
+
     #[test]
     fn shl64() {
         assert_eq!(N << 64, SIXTY_FOUR);
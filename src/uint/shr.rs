@@ -1,8 +1,11 @@
 //! [`Uint`] bitwise right shift operations.
+//!
+//! Shifts of [`Uint`] are logical: vacated bits are always filled with zeros.
 
 use super::Uint;
 use crate::{limb::HI_BIT, CtChoice, Limb};
 use core::ops::{Shr, ShrAssign};
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self >> 1` in constant-time, returning the overflowing bit as a `Word` that is either 0...0 or 1...1.
@@ -39,6 +42,10 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Computes `self >> n`.
     ///
+    /// This is a logical shift: the high bits are filled with zeros (there is no sign
+    /// extension, since [`Uint`] is unsigned), and shifting by `Self::BITS` or more returns
+    /// zero. See [`Uint::checked_shr`] to detect over-shifts.
+    ///
     /// NOTE: this operation is variable time with respect to `n` *ONLY*.
     ///
     /// When used with a fixed `n`, this function is constant-time with respect
@@ -96,6 +103,32 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         (lower, new_upper)
     }
+
+    /// Computes `self >> n`, returning the result along with the bits shifted out of the
+    /// bottom, left-aligned in the second [`Uint`], i.e. `(self << Self::BITS) >> n`
+    /// truncated to `Self::BITS` bits, as if computed with double the width.
+    ///
+    /// The carry can be OR'ed into the next (less significant) [`Uint`] of a buffer shifted
+    /// by the same amount, to chain shifts across a multi-[`Uint`] buffer.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub const fn shr_with_carry(&self, n: usize) -> (Self, Self) {
+        let (carry, shifted) = Self::shr_vartime_wide((Self::ZERO, *self), n);
+        (shifted, carry)
+    }
+
+    /// Computes `self >> n`, returning none if `n >= Self::BITS`.
+    ///
+    /// NOTE: this operation is variable time with respect to `n` *ONLY*.
+    ///
+    /// When used with a fixed `n`, this function is constant-time with respect
+    /// to `self`.
+    pub fn checked_shr(&self, n: usize) -> CtOption<Self> {
+        CtOption::new(self.shr_vartime(n), ((n < Self::BITS) as u8).into())
+    }
 }
 
 impl<const LIMBS: usize> Shr<usize> for Uint<LIMBS> {
@@ -143,6 +176,45 @@ mod tests {
         assert_eq!(N >> 1, N_2);
     }
 
+    #[test]
+    fn shr_is_logical() {
+        assert_eq!(U256::MAX >> 255, U256::ONE);
+        assert_eq!(U256::MAX >> 256, U256::ZERO);
+    }
+
+    #[test]
+    fn shr_with_carry() {
+        let x = U128::from_be_hex("0123456789abcdef0011223344556677");
+        assert_eq!(x.shr_with_carry(0), (x, U128::ZERO));
+        assert_eq!(
+            x.shr_with_carry(8),
+            (
+                U128::from_be_hex("000123456789abcdef00112233445566"),
+                U128::from_be_hex("77000000000000000000000000000000")
+            )
+        );
+        assert_eq!(x.shr_with_carry(128), (U128::ZERO, x));
+        assert_eq!(x.shr_with_carry(300), (U128::ZERO, U128::ZERO));
+
+        // Chaining across a two-element buffer matches a shift of the wider integer
+        let wide =
+            U256::from_be_hex("fedcba98765432100123456789abcdef0123456789abcdeffedcba9876543210");
+        let (hi, lo) = wide.split();
+        for n in [1, 63, 64, 65, 127] {
+            let (new_hi, carry) = hi.shr_with_carry(n);
+            let (new_lo, _) = lo.shr_with_carry(n);
+            assert_eq!(U256::from((new_lo.bitor(&carry), new_hi)), wide >> n);
+        }
+    }
+
+    #[test]
+    fn checked_shr() {
+        assert_eq!(N.checked_shr(1).unwrap(), N_2);
+        assert_eq!(U256::MAX.checked_shr(255).unwrap(), U256::ONE);
+        assert!(bool::from(U256::MAX.checked_shr(256).is_none()));
+        assert!(bool::from(U256::MAX.checked_shr(usize::MAX).is_none()));
+    }
+
     #[test]
     fn shr_wide_1_1_128() {
         assert_eq!(
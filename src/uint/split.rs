@@ -1,3 +1,5 @@
+use super::Uint;
+
 // TODO(tarcieri): use `const_evaluatable_checked` when stable to make generic around bits.
 macro_rules! impl_split {
     ($(($name:ident, $bits:expr)),+) => {
@@ -45,9 +47,42 @@ macro_rules! impl_split {
      };
 }
 
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Split this number into three limb-aligned parts, returning its high, middle
+    /// and low components respectively, with `A`, `B` and `C` limbs.
+    ///
+    /// Panics if `A + B + C != LIMBS`.
+    pub const fn split3<const A: usize, const B: usize, const C: usize>(
+        &self,
+    ) -> (Uint<A>, Uint<B>, Uint<C>) {
+        assert!(
+            A + B + C == LIMBS,
+            "the parts must add up to the size of the integer"
+        );
+
+        let mut lo = Uint::<C>::ZERO;
+        let mut mid = Uint::<B>::ZERO;
+        let mut hi = Uint::<A>::ZERO;
+        let mut i = 0;
+
+        while i < LIMBS {
+            if i < C {
+                lo.limbs[i] = self.limbs[i];
+            } else if i < C + B {
+                mid.limbs[i - C] = self.limbs[i];
+            } else {
+                hi.limbs[i - C - B] = self.limbs[i];
+            }
+            i += 1;
+        }
+
+        (hi, mid, lo)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{U128, U64};
+    use crate::{U128, U192, U256, U64, U768};
 
     #[test]
     fn split() {
@@ -55,4 +90,40 @@ mod tests {
         assert_eq!(hi, U64::from_u64(0x0011223344556677));
         assert_eq!(lo, U64::from_u64(0x8899aabbccddeeff));
     }
+
+    #[test]
+    fn split3() {
+        let (hi, mid, lo) = U192::from_be_hex("00112233445566778899aabbccddeeff0123456789abcdef")
+            .split3::<{ U64::LIMBS }, { U64::LIMBS }, { U64::LIMBS }>();
+        assert_eq!(hi, U64::from_u64(0x0011223344556677));
+        assert_eq!(mid, U64::from_u64(0x8899aabbccddeeff));
+        assert_eq!(lo, U64::from_u64(0x0123456789abcdef));
+
+        // Uneven parts
+        let x =
+            U256::from_be_hex("00112233445566778899aabbccddeeff0123456789abcdeffedcba9876543210");
+        let (hi, mid, lo) = x.split3::<{ U64::LIMBS }, { U64::LIMBS }, { U128::LIMBS }>();
+        assert_eq!(hi, U64::from_u64(0x0011223344556677));
+        assert_eq!(mid, U64::from_u64(0x8899aabbccddeeff));
+        assert_eq!(lo, U128::from_be_hex("0123456789abcdeffedcba9876543210"));
+        let (hi, mid, lo) = x.split3::<{ U128::LIMBS }, { U64::LIMBS }, { U64::LIMBS }>();
+        assert_eq!(hi, U128::from_be_hex("00112233445566778899aabbccddeeff"));
+        assert_eq!(mid, U64::from_u64(0x0123456789abcdef));
+        assert_eq!(lo, U64::from_u64(0xfedcba9876543210));
+    }
+
+    #[test]
+    fn split3_thirds() {
+        let x = U768::MAX.shr_vartime(1);
+        let (hi, mid, lo) = x.split3::<{ U256::LIMBS }, { U256::LIMBS }, { U256::LIMBS }>();
+        assert_eq!(hi, U256::MAX.shr_vartime(1));
+        assert_eq!(mid, U256::MAX);
+        assert_eq!(lo, U256::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "the parts must add up to the size of the integer")]
+    fn split3_wrong_size() {
+        let _ = U256::ONE.split3::<1, 1, 1>();
+    }
 }
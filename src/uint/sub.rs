@@ -7,6 +7,8 @@ use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `a - (b + borrow)`, returning the result along with the new borrow.
+    ///
+    /// The borrow is propagated arithmetically and never influences control flow.
     #[inline(always)]
     pub const fn sbb(&self, rhs: &Self, mut borrow: Limb) -> (Self, Limb) {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -25,16 +27,13 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// Perform saturating subtraction, returning `ZERO` on underflow.
     pub const fn saturating_sub(&self, rhs: &Self) -> Self {
         let (res, underflow) = self.sbb(rhs, Limb::ZERO);
-
-        if underflow.0 == 0 {
-            res
-        } else {
-            Self::ZERO
-        }
+        Self::ct_select(&res, &Self::ZERO, CtChoice::from_mask(underflow.0))
     }
 
     /// Perform wrapping subtraction, discarding underflow and wrapping around
-    /// the boundary of the type.
+    /// the boundary of the type, i.e. `self < rhs` results in `2^BITS - (rhs - self)`.
+    ///
+    /// This is a `const fn`, e.g. `const N_MINUS_ONE: U256 = N.wrapping_sub(&U256::ONE);`.
     pub const fn wrapping_sub(&self, rhs: &Self) -> Self {
         self.sbb(rhs, Limb::ZERO).0
     }
@@ -190,6 +189,44 @@ mod tests {
         assert_eq!(U128::ZERO.wrapping_sub(&U128::ONE), U128::MAX);
     }
 
+    #[test]
+    fn wrapping_sub_smaller_minus_larger() {
+        let cases = [
+            (0u128, 1u128),
+            (5, 17),
+            (1 << 64, (1 << 64) + 1),
+            (0x0123_4567_89ab_cdef, u128::MAX),
+        ];
+
+        for (a, b) in cases {
+            let (x, y) = (U128::from_u128(a), U128::from_u128(b));
+            // `2^128 - (b - a)`
+            let expected = U128::from_u128(0u128.wrapping_sub(b - a));
+            assert_eq!(x.wrapping_sub(&y), expected);
+            assert_eq!(y.wrapping_sub(&x), U128::from_u128(b - a));
+        }
+    }
+
+    #[test]
+    fn wrapping_sub_const() {
+        const RESULT: U128 = U128::from_u8(3).wrapping_sub(&U128::from_u8(5));
+        assert_eq!(RESULT, U128::MAX.wrapping_sub(&U128::ONE));
+    }
+
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(
+            U128::from_u8(5).saturating_sub(&U128::from_u8(3)),
+            U128::from_u8(2)
+        );
+        assert_eq!(
+            U128::from_u8(3).saturating_sub(&U128::from_u8(5)),
+            U128::ZERO
+        );
+        assert_eq!(U128::ZERO.saturating_sub(&U128::MAX), U128::ZERO);
+        assert_eq!(U128::MAX.saturating_sub(&U128::MAX), U128::ZERO);
+    }
+
     #[test]
     fn checked_sub_ok() {
         let result = U128::ONE.checked_sub(&U128::ONE);
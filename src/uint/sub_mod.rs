@@ -1,11 +1,15 @@
 //! [`Uint`] subtraction modulus operations.
 
-use crate::{Limb, SubMod, Uint};
+use crate::{CtChoice, Limb, NonZero, SubMod, Uint};
+use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self - rhs mod p` in constant time.
     ///
-    /// Assumes `self - rhs` as unbounded signed integer is in `[-p, p)`.
+    /// Assumes `self - rhs` as unbounded signed integer is in `[-p, p)`, which holds when
+    /// both operands are reduced modulo `p`. Otherwise the result is silently wrong (this
+    /// is checked by a debug assertion when `self - rhs >= p`); use [`Uint::sub_mod_reduce`]
+    /// for operands which may not be reduced.
     pub const fn sub_mod(&self, rhs: &Uint<LIMBS>, p: &Uint<LIMBS>) -> Uint<LIMBS> {
         let (mut out, borrow) = self.sbb(rhs, Limb::ZERO);
 
@@ -21,9 +25,35 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             i += 1;
         }
 
+        debug_assert!(
+            Uint::ct_lt(&out, p).is_true_vartime(),
+            "operands must be reduced"
+        );
         out
     }
 
+    /// Computes `self - rhs mod p` in constant time, returning none if either operand
+    /// is not reduced, i.e. if `self >= p` or `rhs >= p`.
+    ///
+    /// Unlike [`Uint::sub_mod`], whose result is silently wrong for unreduced operands
+    /// in release builds, misuse is always caught. The check is performed in constant time.
+    pub fn checked_sub_mod(&self, rhs: &Self, p: &Self) -> CtOption<Self> {
+        let is_reduced = Self::ct_lt(self, p).and(Self::ct_lt(rhs, p));
+
+        // Substitute valid inputs (also covering `p == 0`) so that the debug assertion
+        // of `sub_mod` holds
+        let lhs = Self::ct_select(&Self::ZERO, self, is_reduced);
+        let rhs = Self::ct_select(&Self::ZERO, rhs, is_reduced);
+        let p = Self::ct_select(&Self::MAX, p, is_reduced);
+        CtOption::new(lhs.sub_mod(&rhs, &p), is_reduced.into())
+    }
+
+    /// Computes `self - rhs mod p` in constant time, first reducing both operands
+    /// modulo `p` so that they can have any value.
+    pub fn sub_mod_reduce(&self, rhs: &Self, p: &NonZero<Self>) -> Self {
+        self.rem(p).sub_mod(&rhs.rem(p), p)
+    }
+
     /// Computes `self - rhs mod p` in constant time for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -38,6 +68,32 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         let (out, _) = out.sbb(&Uint::from_word(l), Limb::ZERO);
         out
     }
+
+    /// Subtracts `modulus` from `self` if `self >= modulus`, in constant time.
+    ///
+    /// This is the final reduction step of modular addition: if `self < 2 * modulus`,
+    /// the result is `self mod modulus`.
+    pub const fn sub_mod_if_ge(&self, modulus: &Self) -> Self {
+        let (diff, borrow) = self.sbb(modulus, Limb::ZERO);
+        Self::ct_select(&diff, self, CtChoice::from_mask(borrow.0))
+    }
+
+    /// Reduces `self` mod `modulus` with `n` conditional subtractions of `modulus`,
+    /// e.g. to finalize the result of lazy modular arithmetic.
+    ///
+    /// Assumes `self < (n + 1) * modulus`. All `n` subtractions are always performed,
+    /// so this is constant time with respect to `self` and `modulus` (but not `n`).
+    pub const fn conditional_reduce_n(&self, modulus: &Self, n: usize) -> Self {
+        let mut out = *self;
+
+        let mut i = 0;
+        while i < n {
+            out = out.sub_mod_if_ge(modulus);
+            i += 1;
+        }
+
+        out
+    }
 }
 
 impl<const LIMBS: usize> SubMod for Uint<LIMBS> {
@@ -55,6 +111,89 @@ mod tests {
     use crate::{Limb, NonZero, Random, RandomMod, Uint};
     use rand_core::SeedableRng;
 
+    #[test]
+    fn sub_mod_reduce() {
+        let p = NonZero::new(Uint::<2>::from_u64(1000)).unwrap();
+        let a = Uint::<2>::from_u64(2001);
+        let b = Uint::<2>::from_u64(5999);
+        assert_eq!(a.sub_mod_reduce(&b, &p), Uint::from_u64(2));
+        assert_eq!(b.sub_mod_reduce(&a, &p), Uint::from_u64(998));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "operands must be reduced")]
+    fn sub_mod_unreduced() {
+        let p = Uint::<2>::from_u64(1000);
+        let _ = Uint::<2>::from_u64(1500).sub_mod(&Uint::from_u64(200), &p);
+    }
+
+    #[test]
+    fn checked_sub_mod() {
+        let p = Uint::<2>::from_u64(1000);
+        let (a, b) = (Uint::<2>::from_u64(600), Uint::<2>::from_u64(999));
+        assert_eq!(a.checked_sub_mod(&b, &p).unwrap(), Uint::from_u64(601));
+        assert_eq!(a.checked_sub_mod(&b, &p).unwrap(), a.sub_mod(&b, &p));
+
+        for (a, b) in [(1000u64, 0u64), (0, 1000), (1500, 200), (600, u64::MAX)] {
+            let (a, b) = (Uint::<2>::from_u64(a), Uint::<2>::from_u64(b));
+            assert!(bool::from(a.checked_sub_mod(&b, &p).is_none()));
+        }
+
+        // Zero modulus
+        assert!(bool::from(
+            Uint::<2>::ZERO
+                .checked_sub_mod(&Uint::ZERO, &Uint::ZERO)
+                .is_none()
+        ));
+    }
+
+    #[test]
+    fn sub_mod_if_ge() {
+        let m = Uint::<2>::from_u64(1000);
+        assert_eq!(Uint::<2>::ZERO.sub_mod_if_ge(&m), Uint::ZERO);
+        assert_eq!(
+            Uint::<2>::from_u64(999).sub_mod_if_ge(&m),
+            Uint::from_u64(999)
+        );
+        assert_eq!(Uint::<2>::from_u64(1000).sub_mod_if_ge(&m), Uint::ZERO);
+        assert_eq!(
+            Uint::<2>::from_u64(1999).sub_mod_if_ge(&m),
+            Uint::from_u64(999)
+        );
+
+        // Together with `conditional_add`, this gives a conditional modular addition
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let p = NonZero::new(Uint::<2>::MAX.shr_vartime(1)).unwrap();
+        for _ in 0..100 {
+            let a = Uint::<2>::random_mod(&mut rng, &p);
+            let b = Uint::<2>::random_mod(&mut rng, &p);
+            let sum = a.conditional_add(&b, 1.into()).sub_mod_if_ge(&p);
+            assert_eq!(sum, a.add_mod(&b, &p));
+            assert_eq!(a.conditional_add(&b, 0.into()).sub_mod_if_ge(&p), a);
+        }
+    }
+
+    #[test]
+    fn conditional_reduce_n() {
+        let m = Uint::<2>::from_u64(1000);
+
+        for n in 0..4u64 {
+            // Inputs in each band `[k * m, (k + 1) * m)` for `k <= n`
+            for k in 0..=n {
+                for r in [0u64, 1, 999] {
+                    let x = Uint::<2>::from_u64(k * 1000 + r);
+                    assert_eq!(x.conditional_reduce_n(&m, n as usize), Uint::from_u64(r));
+                }
+            }
+        }
+
+        // Full width values
+        let m = Uint::<2>::MAX.shr_vartime(2).wrapping_add(&Uint::ONE);
+        let x = Uint::<2>::MAX;
+        assert_eq!(x.conditional_reduce_n(&m, 3), m.wrapping_sub(&Uint::ONE));
+    }
+
     macro_rules! test_sub_mod {
         ($size:expr, $test_name:ident) => {
             #[test]
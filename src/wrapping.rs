@@ -68,6 +68,10 @@ impl<T: Random> Random for Wrapping<T> {
     fn random(rng: &mut impl CryptoRngCore) -> Self {
         Wrapping(Random::random(rng))
     }
+
+    fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        Random::try_random(rng).map(Wrapping)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,10 @@
+//! Test to ensure that `define_residue!` works from outside this crate.
+
+use crypto_bigint::{const_residue, define_residue, modular::constant_mod::ResidueParams, U64};
+
+define_residue!(TestMod, modulus = "30e4b8f030ab42f3");
+
+fn _test_fun() {
+    let base = U64::from(2u64);
+    let _base_mod = const_residue!(base, TestMod);
+}
@@ -0,0 +1,143 @@
+//! Known-answer tests for modular exponentiation.
+//!
+//! Vectors are loaded from `tests/vectors/modexp_kat.txt` and cover the edge exponents
+//! `0`, `1` and `m - 1`, the bases `0`, `1` and `m - 1`, and random values.
+
+use crypto_bigint::{
+    impl_modulus,
+    modular::{
+        constant_mod::{Residue, ResidueParams},
+        runtime_mod::{DynResidue, DynResidueParams},
+    },
+    Uint, U256, U512, U64,
+};
+
+const VECTORS: &str = include_str!("vectors/modexp_kat.txt");
+
+impl_modulus!(M64, U64, "ffffffffffffffc5");
+impl_modulus!(
+    P256,
+    U256,
+    "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+);
+impl_modulus!(
+    K256,
+    U256,
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+);
+impl_modulus!(
+    M512,
+    U512,
+    "e4e075f44806aa98fba4cd3b264774e1a636beb2f4bd2eeab0c923775e24d383\
+     304415eb1a10d3d568f9cffbffe27f902a22350f017a60b948ffcebfc549ec3b"
+);
+
+/// A single `R = B^E mod M` vector.
+struct Vector<'a> {
+    base: &'a str,
+    exponent: &'a str,
+    result: &'a str,
+}
+
+/// A named modulus along with its vectors.
+struct Section<'a> {
+    name: &'a str,
+    modulus: &'a str,
+    vectors: Vec<Vector<'a>>,
+}
+
+fn parse_vectors(input: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section<'_>> = Vec::new();
+    let mut fields: Vec<(&str, &str)> = Vec::new();
+
+    for line in input.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push(Section {
+                name,
+                modulus: "",
+                vectors: Vec::new(),
+            });
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').expect("malformed line");
+        fields.push((key.trim(), value.trim()));
+
+        let section = sections.last_mut().expect("record outside of a section");
+        match fields.as_slice() {
+            [("M", modulus)] => {
+                section.modulus = modulus;
+                fields.clear();
+            }
+            [("B", base), ("E", exponent), ("R", result)] => {
+                section.vectors.push(Vector {
+                    base,
+                    exponent,
+                    result,
+                });
+                fields.clear();
+            }
+            [("B", _)] | [("B", _), ("E", _)] => {}
+            _ => panic!("malformed record in section {}", section.name),
+        }
+    }
+
+    assert!(fields.is_empty(), "incomplete record at end of input");
+    sections
+}
+
+fn check_section<MOD: ResidueParams<LIMBS>, const LIMBS: usize>(section: &Section<'_>) {
+    assert_eq!(Uint::<LIMBS>::from_be_hex(section.modulus), MOD::MODULUS);
+    assert!(!section.vectors.is_empty());
+
+    let params = DynResidueParams::new(&MOD::MODULUS);
+
+    for (i, vector) in section.vectors.iter().enumerate() {
+        let base = Uint::<LIMBS>::from_be_hex(vector.base);
+        let exponent = Uint::<LIMBS>::from_be_hex(vector.exponent);
+        let expected = Uint::<LIMBS>::from_be_hex(vector.result);
+
+        let residue = Residue::<MOD, LIMBS>::new(&base);
+        assert_eq!(
+            residue.pow(&exponent).retrieve(),
+            expected,
+            "{} vector #{}: Residue::pow",
+            section.name,
+            i
+        );
+        assert_eq!(
+            residue.pow_vartime(&exponent).retrieve(),
+            expected,
+            "{} vector #{}: Residue::pow_vartime",
+            section.name,
+            i
+        );
+        assert_eq!(
+            DynResidue::new(&base, params).pow(&exponent).retrieve(),
+            expected,
+            "{} vector #{}: DynResidue::pow",
+            section.name,
+            i
+        );
+    }
+}
+
+#[test]
+fn modexp_kat() {
+    let sections = parse_vectors(VECTORS);
+    assert_eq!(sections.len(), 4);
+
+    for section in &sections {
+        match section.name {
+            "M64" => check_section::<M64, { U64::LIMBS }>(section),
+            "P256" => check_section::<P256, { U256::LIMBS }>(section),
+            "K256" => check_section::<K256, { U256::LIMBS }>(section),
+            "M512" => check_section::<M512, { U512::LIMBS }>(section),
+            name => panic!("unknown modulus: {}", name),
+        }
+    }
+}
@@ -8,6 +8,8 @@ use num_bigint::BigUint;
 use num_integer::Integer;
 use num_traits::identities::Zero;
 use proptest::prelude::*;
+#[cfg(feature = "rand")]
+use rand_core::OsRng;
 use std::mem;
 
 /// Example prime number (NIST P-256 curve order)
@@ -186,6 +188,16 @@ proptest! {
         }
     }
 
+    #[test]
+    fn gcd(a in uint(), b in uint()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let expected = to_uint(a_bi.gcd(&b_bi));
+        assert_eq!(expected, a.gcd(&b));
+        assert_eq!(expected, a.gcd_vartime(&b));
+    }
+
     #[test]
     fn wrapping_sqrt(a in uint()) {
         let a_bi = to_biguint(&a);
@@ -282,4 +294,43 @@ proptest! {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn residue_pow_blinded(a in uint_mod_p(P), b in uint()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let p_bi = to_biguint(&P);
+
+        let expected = to_uint(a_bi.modpow(&b_bi, &p_bi));
+
+        let params = DynResidueParams::new(&P);
+        let a_m = DynResidue::new(&a, params);
+        let group_order = P.wrapping_sub(&U256::ONE);
+        let actual = a_m.pow_blinded(&b, &group_order, &mut OsRng).retrieve();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn pow_mod(a in uint(), b in uint(), m in uint()) {
+        let m_bi = to_biguint(&m);
+        if !m_bi.is_zero() {
+            let expected = to_uint(to_biguint(&a).modpow(&to_biguint(&b), &m_bi));
+            let actual = a.pow_mod(&b, &NonZero::new(m).unwrap());
+
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn pow_barrett(a in uint(), b in uint(), m in uint()) {
+        let m_bi = to_biguint(&m);
+        if !m_bi.is_zero() {
+            let expected = to_uint(to_biguint(&a).modpow(&to_biguint(&b), &m_bi));
+            let actual = a.pow_barrett(&b, &NonZero::new(m).unwrap());
+
+            assert_eq!(expected, actual);
+        }
+    }
 }